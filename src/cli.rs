@@ -0,0 +1,349 @@
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::solvers::InputSource;
+use crate::solvers::bench::BenchConfig;
+use crate::utils::graph::GraphFormat;
+
+pub const USAGE: &str = "\
+Usage: advent_of_code <command> [<selection>] [<options>]
+       advent_of_code <year> <day> [<part>]
+
+Commands:
+  run <selection>      Run solvers, a single day prints detailed results,
+                       several days print a summary table
+  verify <selection>   Compare results against inputs/<year>/answers.txt
+  bench <selection>    Run solvers repeatedly and report timing statistics
+  new <year> <day>     Create a day module from the template and register it
+  list [<selection>]   List registered solvers
+  help                 Print this message
+
+Selection:
+  all                  Every registered solver
+  <year>               Every solver of a year
+  <year> <day>         A single day
+  <year> <from>-<to>   A range of days
+
+Options:
+  --input <path>       Read the puzzle input from <path>, or stdin if <path> is `-`
+                       (single day only)
+  --example <n>        Use the n-th example input of the day's tests (single day only)
+  --part <1|2>         Only run the given part
+  --quiet              Only print results
+  --json               Print runs as JSON, one object per line for several days,
+                       integer values are numbers up to +/-(2^53 - 1) and strings
+                       of digits beyond
+  --graph <path>       Write the day's graph to <path> as Graphviz (.dot, .gv) or
+                       Mermaid (.mmd, .mermaid), for days exporting one (single day only)
+
+Bench options:
+  --runs <n>           Measured runs per stage (default: 10)
+  --warmup <n>         Unmeasured runs per stage (default: 1)
+  --baseline <path>    Compare medians against a saved baseline
+  --save-baseline <path>
+                       Save medians to a baseline file
+  --threshold <pct>    Median increase flagged as regression (default: 10)";
+
+pub enum Command {
+    Run(Selection),
+    Verify(Selection),
+    Bench(Selection),
+    New(u32, u32),
+    List(Selection),
+    Help,
+}
+
+pub struct Selection {
+    pub year: Option<u32>,
+    pub days: Option<RangeInclusive<u32>>,
+}
+
+impl Selection {
+    pub fn all() -> Self {
+        Selection {
+            year: None,
+            days: None,
+        }
+    }
+
+    pub fn single_day(&self) -> Option<(u32, u32)> {
+        let year = self.year?;
+        let days = self.days.as_ref()?;
+        if days.start() == days.end() {
+            Some((year, *days.start()))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Options {
+    pub input: InputSource,
+    pub part: Option<u32>,
+    pub quiet: bool,
+    pub json: bool,
+    pub graph: Option<(String, GraphFormat)>,
+    pub bench: BenchConfig,
+}
+
+impl Options {
+    pub fn run_part_1(&self) -> bool {
+        self.part != Some(2)
+    }
+
+    pub fn run_part_2(&self) -> bool {
+        self.part != Some(1)
+    }
+}
+
+pub struct Cli {
+    pub command: Command,
+    pub options: Options,
+}
+
+#[derive(Debug)]
+pub struct CliError {
+    msg: String,
+}
+
+impl CliError {
+    pub fn new(s: impl ToString) -> Self {
+        CliError { msg: s.to_string() }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+impl Cli {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, CliError> {
+        let mut positionals = Vec::new();
+        let mut options = Options::default();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let path = args.next().ok_or(CliError::new("missing value for --input"))?;
+                    options.input = if path == "-" { InputSource::Stdin } else { InputSource::File(path) };
+                },
+                "--example" | "-e" => {
+                    let example: usize = parse_option_value(&arg, args.next())?;
+                    if example == 0 {
+                        return Err(CliError::new("invalid value for --example: 0 (examples are numbered from 1)"));
+                    }
+                    options.input = InputSource::Example(example - 1);
+                },
+                "--part" | "-p" => {
+                    let part = args.next().ok_or(CliError::new("missing value for --part"))?;
+                    options.part = Some(parse_part(&part)?);
+                },
+                "--quiet" | "-q" => options.quiet = true,
+                "--json" => options.json = true,
+                "--graph" => {
+                    let path: String = parse_option_value(&arg, args.next())?;
+                    let format = GraphFormat::from_path(&path)
+                        .ok_or(CliError::new(format!("unknown graph format: {path} (expected .dot, .gv, .mmd or .mermaid)")))?;
+                    options.graph = Some((path, format));
+                },
+                "--runs" => options.bench.runs = parse_option_value(&arg, args.next())?,
+                "--warmup" => options.bench.warmup = parse_option_value(&arg, args.next())?,
+                "--baseline" => options.bench.baseline = Some(parse_option_value(&arg, args.next())?),
+                "--save-baseline" => options.bench.save_baseline = Some(parse_option_value(&arg, args.next())?),
+                "--threshold" => options.bench.threshold = parse_option_value(&arg, args.next())?,
+                "--help" | "-h" => positionals.insert(0, "help".to_string()),
+                _ if arg.starts_with('-') && arg.len() > 1 => {
+                    return Err(CliError::new(format!("unknown option: {arg}")));
+                },
+                _ => positionals.push(arg),
+            }
+        }
+
+        let Some((command, args)) = positionals.split_first() else {
+            return Ok(Cli { command: Command::Help, options });
+        };
+
+        let command = match command.as_str() {
+            "run" => Command::Run(parse_selection(args)?),
+            "verify" => Command::Verify(parse_selection(args)?),
+            "bench" => Command::Bench(parse_selection(args)?),
+            "new" => {
+                let [year, day] = args else {
+                    return Err(CliError::new("expected `new <year> <day>`"));
+                };
+                let days = parse_days(day)?;
+                if days.start() != days.end() {
+                    return Err(CliError::new(format!("invalid day: {day}")));
+                }
+                Command::New(parse_year(year)?, *days.start())
+            },
+            "list" if args.is_empty() => Command::List(Selection::all()),
+            "list" => Command::List(parse_selection(args)?),
+            "help" => Command::Help,
+            // Legacy form: `<year> <day> [<part>]`
+            _ if command.parse::<u32>().is_ok() => {
+                if args.len() > 2 {
+                    return Err(CliError::new(format!("unexpected arguments: {}", args[2..].join(" "))));
+                }
+                if args.len() == 2 {
+                    if options.part.is_some() {
+                        return Err(CliError::new("part given both as argument and as --part option"));
+                    }
+                    options.part = Some(parse_part(&args[1])?);
+                }
+                Command::Run(parse_selection(&positionals[..positionals.len().min(2)])?)
+            },
+            _ => return Err(CliError::new(format!("unknown command: {command}"))),
+        };
+
+        if options.input != InputSource::Default {
+            let is_single_day = match &command {
+                Command::Run(selection) | Command::Bench(selection) => selection.single_day().is_some(),
+                _ => false,
+            };
+            if !is_single_day {
+                return Err(CliError::new("--input and --example can only be used to run or bench a single day"));
+            }
+        }
+
+        if options.graph.is_some() && !matches!(&command, Command::Run(selection) if selection.single_day().is_some()) {
+            return Err(CliError::new("--graph can only be used to run a single day"));
+        }
+        if options.graph.is_some() && options.input == InputSource::Stdin {
+            return Err(CliError::new("--graph cannot be used with input read from stdin"));
+        }
+
+        if options.json && !matches!(command, Command::Run(_)) {
+            return Err(CliError::new("--json can only be used with the run command"));
+        }
+
+        Ok(Cli { command, options })
+    }
+}
+
+fn parse_option_value<T: FromStr>(option: &str, value: Option<String>) -> Result<T, CliError> {
+    let value = value.ok_or(CliError::new(format!("missing value for {option}")))?;
+    value.parse().map_err(|_| CliError::new(format!("invalid value for {option}: {value}")))
+}
+
+fn parse_part(s: &str) -> Result<u32, CliError> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(CliError::new(format!("invalid part: {s} (expected 1 or 2)"))),
+    }
+}
+
+fn parse_selection(args: &[String]) -> Result<Selection, CliError> {
+    match args {
+        [all] if all == "all" => Ok(Selection::all()),
+        [year] => Ok(Selection {
+            year: Some(parse_year(year)?),
+            days: None,
+        }),
+        [year, days] => Ok(Selection {
+            year: Some(parse_year(year)?),
+            days: Some(parse_days(days)?),
+        }),
+        [] => Err(CliError::new("missing selection (expected `all`, `<year>` or `<year> <days>`)")),
+        _ => Err(CliError::new(format!("unexpected arguments: {}", args[2..].join(" ")))),
+    }
+}
+
+fn parse_year(s: &str) -> Result<u32, CliError> {
+    s.parse().map_err(|_| CliError::new(format!("invalid year: {s}")))
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<u32>, CliError> {
+    let parse_day = |s: &str| -> Result<u32, CliError> {
+        match s.parse() {
+            Ok(day) if (1..=25).contains(&day) => Ok(day),
+            _ => Err(CliError::new(format!("invalid day: {s} (expected 1 to 25)"))),
+        }
+    };
+
+    match s.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                return Err(CliError::new(format!("invalid day range: {s}")));
+            }
+            Ok(from..=to)
+        },
+        None => {
+            let day = parse_day(s)?;
+            Ok(day..=day)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Cli, CliError> {
+        Cli::parse(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse() {
+        let cli = parse("run 2023 4 --part 2 --quiet").unwrap();
+        let Command::Run(selection) = cli.command else { panic!() };
+        assert_eq!(selection.single_day(), Some((2023, 4)));
+        assert!(!cli.options.run_part_1());
+        assert!(cli.options.quiet);
+
+        let cli = parse("run 2023 4 --example 2").unwrap();
+        assert_eq!(cli.options.input, InputSource::Example(1));
+        let cli = parse("run 2023 4 --input -").unwrap();
+        assert_eq!(cli.options.input, InputSource::Stdin);
+        assert!(parse("run 2023 --json").unwrap().options.json);
+        let cli = parse("run 2023 25 --graph wiring.dot").unwrap();
+        assert_eq!(cli.options.graph, Some(("wiring.dot".to_string(), GraphFormat::Dot)));
+
+        let cli = parse("2021 13 1").unwrap();
+        let Command::Run(selection) = cli.command else { panic!() };
+        assert_eq!(selection.single_day(), Some((2021, 13)));
+        assert_eq!(cli.options.part, Some(1));
+
+        let cli = parse("verify 2023 1-10").unwrap();
+        let Command::Verify(selection) = cli.command else { panic!() };
+        assert_eq!(selection.year, Some(2023));
+        assert_eq!(selection.days, Some(1..=10));
+
+        let cli = parse("bench 2023 --runs 5 --threshold 2.5").unwrap();
+        assert!(matches!(cli.command, Command::Bench(_)));
+        assert_eq!(cli.options.bench.runs, 5);
+        assert_eq!(cli.options.bench.threshold, 2.5);
+
+        assert!(matches!(parse("new 2022 1").unwrap().command, Command::New(2022, 1)));
+        assert!(matches!(parse("list").unwrap().command, Command::List(_)));
+        assert!(matches!(parse("").unwrap().command, Command::Help));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("run").is_err());
+        assert!(parse("run 20x3").is_err());
+        assert!(parse("run 2023 26").is_err());
+        assert!(parse("run 2023 10-1").is_err());
+        assert!(parse("run 2023 1 --part 3").is_err());
+        assert!(parse("run 2023 --input foo.txt").is_err());
+        assert!(parse("run 2023 4 --example 0").is_err());
+        assert!(parse("run all --unknown").is_err());
+        assert!(parse("bench all --runs x").is_err());
+        assert!(parse("verify all --json").is_err());
+        assert!(parse("run 2023 25 --graph wiring.png").is_err());
+        assert!(parse("run 2023 --graph wiring.dot").is_err());
+        assert!(parse("run 2023 25 --graph wiring.dot --input -").is_err());
+        assert!(parse("new 2022 1-2").is_err());
+        assert!(parse("frobnicate").is_err());
+    }
+}
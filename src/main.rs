@@ -1,33 +1,173 @@
+mod cli;
 mod solvers;
 mod utils;
 
 use std::env;
+use std::path::Path;
+use std::process::ExitCode;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 3 {
-        eprint!("Invalid command line arguments count: {}", args.len());
-        return;
-    }
-
-    // Parse args
-    let year = args[1].as_str();
-    let day = args[2].as_str();
-
-    let mut run_part_1 = true;
-    let mut run_part_2 = true;
-    if args.len() > 3 {
-        let part = args[3].parse().unwrap();
-        match part {
-            1 => run_part_2 = false,
-            2 => run_part_1 = false,
-            _ => {
-                eprint!("Invalid part argument: {part}");
-                return;
-            },
+use cli::{Cli, Command, Options, Selection};
+use solvers::{batch, bench, json, registry, scaffold, verify};
+use solvers::InputSource;
+use solvers::registry::SolverInfo;
+use utils::graph::GraphFormat;
+
+const EXIT_FAILURE: u8 = 1;
+const EXIT_USAGE: u8 = 2;
+
+fn main() -> ExitCode {
+    let cli = match Cli::parse(env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(err) => {
+            eprintln!("error: {err}");
+            eprintln!();
+            eprintln!("{}", cli::USAGE);
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+
+    match cli.command {
+        Command::Run(selection) => run(&selection, &cli.options),
+        Command::Verify(selection) => run_verify(&selection, &cli.options),
+        Command::Bench(selection) => run_bench(&selection, &cli.options),
+        Command::New(year, day) => new_day(year, day),
+        Command::List(selection) => list(&selection),
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
+        },
+    }
+}
+
+fn select_solvers(selection: &Selection) -> Result<Vec<&'static SolverInfo>, ExitCode> {
+    let solvers = registry::select(selection.year, selection.days.clone());
+    if solvers.is_empty() {
+        eprintln!("error: no registered solver matches the selection");
+        return Err(ExitCode::from(EXIT_USAGE));
+    }
+    Ok(solvers)
+}
+
+fn run(selection: &Selection, options: &Options) -> ExitCode {
+    // A single day prints detailed results, panics are left uncaught to keep the backtrace unless printing JSON
+    if let Some((year, day)) = selection.single_day() {
+        let Some(info) = registry::find(year, day) else {
+            eprintln!("error: no solver registered for year {year} day {day}");
+            return ExitCode::from(EXIT_USAGE);
+        };
+        if let Some((path, format)) = &options.graph {
+            if let Err(exit_code) = export_graph(info, &options.input, path, *format) {
+                return exit_code;
+            }
+        }
+        if options.json {
+            let run = batch::catch_unwind(|| info.execute(&options.input, options.run_part_1(), options.run_part_2()));
+            println!("{}", json::solver_run_to_json(info, &run));
+            return if run.is_ok() { ExitCode::SUCCESS } else { ExitCode::from(EXIT_FAILURE) };
         }
+        return match info.execute(&options.input, options.run_part_1(), options.run_part_2()) {
+            Ok(run) => {
+                run.print(options.quiet);
+                ExitCode::SUCCESS
+            },
+            Err(err) => {
+                eprintln!("error: {err}");
+                ExitCode::from(EXIT_FAILURE)
+            },
+        };
     }
 
-    // Run solver
-    solvers::run_solver(year, day, run_part_1, run_part_2);
+    let solvers = match select_solvers(selection) {
+        Ok(solvers) => solvers,
+        Err(exit_code) => return exit_code,
+    };
+
+    let failures = batch::run_batch(&solvers, options.run_part_1(), options.run_part_2(), options.quiet, options.json);
+    if failures > 0 {
+        ExitCode::from(EXIT_FAILURE)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn export_graph(info: &SolverInfo, input: &InputSource, path: &str, format: GraphFormat) -> Result<(), ExitCode> {
+    let graph = match info.export_graph(input, format) {
+        Ok(Some(graph)) => graph,
+        Ok(None) => {
+            eprintln!("error: {} day {} doesn't export a graph", info.year(), info.day());
+            return Err(ExitCode::from(EXIT_FAILURE));
+        },
+        Err(err) => {
+            eprintln!("error: {err}");
+            return Err(ExitCode::from(EXIT_FAILURE));
+        },
+    };
+
+    if let Err(err) = std::fs::write(path, graph) {
+        eprintln!("error: fail to write graph at path: {path} ({err})");
+        return Err(ExitCode::from(EXIT_FAILURE));
+    }
+    // Keep stdout for the results
+    eprintln!("Graph written to {path}");
+    Ok(())
+}
+
+fn run_verify(selection: &Selection, options: &Options) -> ExitCode {
+    let solvers = match select_solvers(selection) {
+        Ok(solvers) => solvers,
+        Err(exit_code) => return exit_code,
+    };
+
+    let summary = verify::run_verify(&solvers, options.run_part_1(), options.run_part_2(), options.quiet);
+    if summary.is_success() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_FAILURE)
+    }
+}
+
+fn run_bench(selection: &Selection, options: &Options) -> ExitCode {
+    let solvers = match select_solvers(selection) {
+        Ok(solvers) => solvers,
+        Err(exit_code) => return exit_code,
+    };
+
+    match bench::run_bench(&solvers, &options.input, options.run_part_1(), options.run_part_2(), &options.bench) {
+        Ok(summary) if summary.is_success() => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::from(EXIT_FAILURE),
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(EXIT_FAILURE)
+        },
+    }
+}
+
+fn new_day(year: u32, day: u32) -> ExitCode {
+    match scaffold::create_day(Path::new("."), year, day) {
+        Ok(scaffold) => {
+            for path in scaffold.created.iter() {
+                println!("Created {}", path.display());
+            }
+            for path in scaffold.modified.iter() {
+                println!("Modified {}", path.display());
+            }
+            ExitCode::SUCCESS
+        },
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(EXIT_FAILURE)
+        },
+    }
+}
+
+fn list(selection: &Selection) -> ExitCode {
+    let solvers = match select_solvers(selection) {
+        Ok(solvers) => solvers,
+        Err(exit_code) => return exit_code,
+    };
+
+    for info in solvers {
+        println!("{} day {:>2}  {:<6} {}", info.year(), info.day(), info.name(), info.input_path());
+    }
+    ExitCode::SUCCESS
 }
@@ -1,38 +1,121 @@
-mod year_2020;
-mod year_2021;
-mod year_2023;
+pub mod batch;
+pub mod bench;
+pub mod json;
+pub mod parsing;
+pub mod registry;
+pub mod scaffold;
+pub mod verify;
+
+crate::register_years! {
+    year_2020,
+    year_2021,
+    year_2023,
+}
 
 use std::convert::From;
+use num::{BigInt, BigUint};
 use std::str::FromStr;
 use std::string::ToString;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+use crate::utils::graph::GraphFormat;
 
 mod prelude {
-    pub use crate::solvers::{Solver, SolverResult, ParseSolverError};
+    pub use crate::solvers::{Solver, SolverResult, ParseSolverError, ParseErrorKind};
+    pub use crate::solvers::parsing::{parse_line, parse_lines, sections, LineCursor, Section};
     pub use crate::parse_solver_error;
+    pub use crate::utils::graph::GraphFormat;
+    pub use indoc::indoc;
     pub use std::str::FromStr;
 }
 
 pub trait Solver : FromStr<Err = ParseSolverError> {
     const INPUT_PATH: &'static str;
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[];
     fn run_part1(&self) -> SolverResult;
     fn run_part2(&self) -> SolverResult;
+
+    // Days with a graph shaped input can export it for debugging
+    fn export_graph(&self, _format: GraphFormat) -> Option<String> {
+        None
+    }
 }
 
-#[derive(Default, PartialEq, Eq, Debug)]
+#[derive(Default, Clone, Debug)]
 pub enum SolverResult {
     #[default]
     Invalid,
     I64(i64),
+    U64(u64),
+    U128(u128),
+    BigInt(BigInt),
     String(String),
+    Grid(Vec<String>),
 }
 
 impl SolverResult {
+    #[allow(dead_code)]
+    pub fn from_grid(s: &str) -> Self {
+        SolverResult::Grid(s.lines().map(str::to_string).collect())
+    }
+
+    pub fn to_big_int(&self) -> Option<BigInt> {
+        match self {
+            SolverResult::I64(value) => Some(BigInt::from(*value)),
+            SolverResult::U64(value) => Some(BigInt::from(*value)),
+            SolverResult::U128(value) => Some(BigInt::from(*value)),
+            SolverResult::BigInt(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            SolverResult::Invalid => "invalid",
+            SolverResult::I64(_) => "i64",
+            SolverResult::U64(_) => "u64",
+            SolverResult::U128(_) => "u128",
+            SolverResult::BigInt(_) => "big_int",
+            SolverResult::String(_) => "string",
+            SolverResult::Grid(_) => "grid",
+        }
+    }
+
     pub fn print(&self) {
         match self {
-            SolverResult::Invalid => println!("Result: Invalid"),
-            SolverResult::I64(value) => println!("Result: {value}"),
-            SolverResult::String(value) => println!("Result: {value}"),
+            SolverResult::Grid(_) => println!("Result:\n{self}"),
+            _ => println!("Result: {self}"),
+        }
+    }
+}
+
+// Integers compare by value whatever their variant, so `42u64.into() == 42i32.into()`
+impl PartialEq for SolverResult {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SolverResult::Invalid, SolverResult::Invalid) => true,
+            (SolverResult::String(a), SolverResult::String(b)) => a == b,
+            (SolverResult::Grid(a), SolverResult::Grid(b)) => a == b,
+            _ => match (self.to_big_int(), other.to_big_int()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Eq for SolverResult {}
+
+impl std::fmt::Display for SolverResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SolverResult::Invalid => write!(f, "Invalid"),
+            SolverResult::I64(value) => write!(f, "{value}"),
+            SolverResult::U64(value) => write!(f, "{value}"),
+            SolverResult::U128(value) => write!(f, "{value}"),
+            SolverResult::BigInt(value) => write!(f, "{value}"),
+            SolverResult::String(value) => write!(f, "{value}"),
+            SolverResult::Grid(rows) => write!(f, "{}", rows.join("\n")),
         }
     }
 }
@@ -49,9 +132,21 @@ impl From<i64> for SolverResult {
     }
 }
 
+impl From<i128> for SolverResult {
+    fn from(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => SolverResult::I64(value),
+            Err(_) => SolverResult::BigInt(BigInt::from(value)),
+        }
+    }
+}
+
 impl From<isize> for SolverResult {
     fn from(value: isize) -> Self {
-        SolverResult::I64(value as i64)
+        match i64::try_from(value) {
+            Ok(value) => SolverResult::I64(value),
+            Err(_) => SolverResult::BigInt(BigInt::from(value)),
+        }
     }
 }
 
@@ -63,13 +158,34 @@ impl From<u32> for SolverResult {
 
 impl From<u64> for SolverResult {
     fn from(value: u64) -> Self {
-        SolverResult::I64(value as i64)
+        SolverResult::U64(value)
+    }
+}
+
+impl From<u128> for SolverResult {
+    fn from(value: u128) -> Self {
+        SolverResult::U128(value)
     }
 }
 
 impl From<usize> for SolverResult {
     fn from(value: usize) -> Self {
-        SolverResult::I64(value as i64)
+        match u64::try_from(value) {
+            Ok(value) => SolverResult::U64(value),
+            Err(_) => SolverResult::BigInt(BigInt::from(value)),
+        }
+    }
+}
+
+impl From<BigInt> for SolverResult {
+    fn from(value: BigInt) -> Self {
+        SolverResult::BigInt(value)
+    }
+}
+
+impl From<BigUint> for SolverResult {
+    fn from(value: BigUint) -> Self {
+        SolverResult::BigInt(BigInt::from(value))
     }
 }
 
@@ -85,14 +201,88 @@ impl From<&str> for SolverResult {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Other,
+    InvalidValue,
+    UnexpectedToken,
+    UnexpectedEnd,
+    TrailingInput,
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            ParseErrorKind::Other => "error",
+            ParseErrorKind::InvalidValue => "invalid value",
+            ParseErrorKind::UnexpectedToken => "unexpected token",
+            ParseErrorKind::UnexpectedEnd => "unexpected end of line",
+            ParseErrorKind::TrailingInput => "trailing input",
+        };
+        write!(f, "{s}")
+    }
+}
+
 #[derive(Debug)]
 pub struct ParseSolverError {
-    msg: String
+    kind: ParseErrorKind,
+    msg: String,
+    // 1-based line and column of the error in the input
+    line: Option<usize>,
+    column: Option<usize>,
+    snippet: Option<String>,
 }
 
+#[allow(dead_code)]
 impl ParseSolverError {
     pub fn new(s: impl ToString) -> Self {
-        ParseSolverError { msg: s.to_string() }
+        ParseSolverError::with_kind(ParseErrorKind::Other, s)
+    }
+
+    pub fn with_kind(kind: ParseErrorKind, s: impl ToString) -> Self {
+        ParseSolverError {
+            kind,
+            msg: s.to_string(),
+            line: None,
+            column: None,
+            snippet: None,
+        }
+    }
+
+    // Attach the input location, unless a more precise one was already attached
+    pub fn at(mut self, line: usize, column: Option<usize>, snippet: &str) -> Self {
+        if self.line.is_none() {
+            self.line = Some(line);
+            self.column = column;
+            self.snippet = Some(snippet.to_string());
+        }
+        self
+    }
+
+    // Shift the line, for errors reported relative to a part of the input
+    pub fn offset_line(mut self, offset: usize) -> Self {
+        self.line = self.line.map(|line| line + offset);
+        self
+    }
+
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.msg
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn snippet(&self) -> Option<&str> {
+        self.snippet.as_deref()
     }
 }
 
@@ -102,21 +292,39 @@ impl Error for ParseSolverError {}
 use std::fmt::Display;
 impl Display for ParseSolverError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.msg)
+        if let Some(line) = self.line {
+            write!(f, "line {line}")?;
+            if let Some(column) = self.column {
+                write!(f, ", column {column}")?;
+            }
+            write!(f, ": ")?;
+        }
+        if self.kind != ParseErrorKind::Other {
+            write!(f, "{}: ", self.kind)?;
+        }
+        write!(f, "{}", self.msg)?;
+
+        if let Some(snippet) = &self.snippet {
+            write!(f, "\n    {snippet}")?;
+            if let Some(column) = self.column {
+                write!(f, "\n    {}^", " ".repeat(column - 1))?;
+            }
+        }
+        Ok(())
     }
 }
 
 use std::num::ParseIntError;
 impl From<ParseIntError> for ParseSolverError {
     fn from(err: ParseIntError) -> Self {
-        ParseSolverError::new(err)
+        ParseSolverError::with_kind(ParseErrorKind::InvalidValue, err)
     }
 }
 
 use std::num::ParseFloatError;
 impl From<ParseFloatError> for ParseSolverError {
     fn from(err: ParseFloatError) -> Self {
-        ParseSolverError::new(err)
+        ParseSolverError::with_kind(ParseErrorKind::InvalidValue, err)
     }
 }
 
@@ -127,51 +335,148 @@ macro_rules! parse_solver_error {
     }}
 }
 
-pub fn run_solver(year: &str, day: &str, run_part_1: bool, run_part_2: bool) {
-    match year {
-        "2020" => year_2020::run_solver(day, run_part_1, run_part_2),
-        "2021" => year_2021::run_solver(day, run_part_1, run_part_2),
-        "2023" => year_2023::run_solver(day, run_part_1, run_part_2),
-        _ => panic!("Invalid year argument: {year}"),
+pub struct PartRun {
+    pub result: SolverResult,
+    pub duration: Duration,
+}
+
+pub struct SolverRun {
+    pub parse_duration: Duration,
+    pub part1: Option<PartRun>,
+    pub part2: Option<PartRun>,
+}
+
+impl SolverRun {
+    pub fn print(&self, quiet: bool) {
+        if quiet {
+            for part in self.part1.iter().chain(self.part2.iter()) {
+                println!("{}", part.result);
+            }
+            return;
+        }
+
+        println!("Solver created in {} ms", as_millis(self.parse_duration));
+
+        if let Some(part1) = &self.part1 {
+            println!("Running part 1");
+            part1.result.print();
+            println!("Part 1 executed in {} ms", as_millis(part1.duration));
+        }
+
+        if let Some(part2) = &self.part2 {
+            println!("Running part 2");
+            part2.result.print();
+            println!("Part 2 executed in {} ms", as_millis(part2.duration));
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    #[default]
+    Default,
+    File(String),
+    Stdin,
+    Example(usize),
+}
+
+impl InputSource {
+    pub fn read<T: Solver>(&self) -> Result<String, RunSolverError> {
+        match self {
+            InputSource::Default => read_file(T::INPUT_PATH),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => std::io::read_to_string(std::io::stdin())
+                .map_err(|err| RunSolverError::ReadInput("stdin".to_string(), err)),
+            InputSource::Example(index) => T::EXAMPLE_INPUTS
+                .get(*index)
+                .map(|example| example.to_string())
+                .ok_or(RunSolverError::MissingExample(*index, T::EXAMPLE_INPUTS.len())),
+        }
+    }
+}
+
+fn read_file(path: &str) -> Result<String, RunSolverError> {
+    std::fs::read_to_string(path)
+        .map_err(|err| RunSolverError::ReadInput(format!("path: {path}"), err))
+}
+
+#[derive(Debug)]
+pub enum RunSolverError {
+    ReadInput(String, std::io::Error),
+    MissingExample(usize, usize),
+    Parse(ParseSolverError),
+    Panic(String),
+}
+
+impl Error for RunSolverError {}
+
+impl Display for RunSolverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RunSolverError::ReadInput(source, err) => write!(f, "Fail to read input from {source} ({err})"),
+            RunSolverError::MissingExample(index, count) => write!(f, "No example input {} ({count} available)", index + 1),
+            RunSolverError::Parse(err) => write!(f, "Fail to create solver from input: {err}"),
+            RunSolverError::Panic(msg) => write!(f, "Solver panicked: {msg}"),
+        }
     }
 }
 
-pub fn run_solver_generic<T: Solver>(run_part_1: bool, run_part_2: bool) {
-    let Ok(input) = std::fs::read_to_string(T::INPUT_PATH) else {
-        eprint!("Fail to read input at path: {}", T::INPUT_PATH);
-        return;
-    };
+pub fn as_millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 * 0.001
+}
+
+pub fn execute_solver<T: Solver>(input: &InputSource, run_part_1: bool, run_part_2: bool) -> Result<SolverRun, RunSolverError> {
+    let input = input.read::<T>()?;
 
     // Create solver
     let now = Instant::now();
-    let solver = input.parse::<T>();
-    let solver = match solver {
-        Ok(solver) => solver,
-        Err(err) => {
-            eprint!("Fail to create solver from input: {err}");
-            return;
-        }
-    };
-    let duration = now.elapsed().as_micros() as f64 * 0.001;
-    println!("Solver created in {duration} ms");
-
-    if run_part_1 {
-        // Run part 1
-        println!("Running part 1");
-        let now = Instant::now();
-        let result = solver.run_part1();
-        let duration = now.elapsed().as_micros() as f64 * 0.001;
-        result.print();
-        println!("Part 1 executed in {duration} ms");
-    }
-
-    if run_part_2 {
-        // Run part 2
-        println!("Running part 2");
-        let now = Instant::now();
-        let result = solver.run_part2();
-        let duration = now.elapsed().as_micros() as f64 * 0.001;
-        result.print();
-        println!("Part 2 executed in {duration} ms");
+    let solver = input.parse::<T>().map_err(RunSolverError::Parse)?;
+    let parse_duration = now.elapsed();
+
+    let part1 = run_part_1.then(|| run_part(|| solver.run_part1()));
+    let part2 = run_part_2.then(|| run_part(|| solver.run_part2()));
+
+    Ok(SolverRun {
+        parse_duration,
+        part1,
+        part2,
+    })
+}
+
+pub fn export_solver_graph<T: Solver>(input: &InputSource, format: GraphFormat) -> Result<Option<String>, RunSolverError> {
+    let input = input.read::<T>()?;
+    let solver = input.parse::<T>().map_err(RunSolverError::Parse)?;
+    Ok(solver.export_graph(format))
+}
+
+fn run_part(f: impl FnOnce() -> SolverResult) -> PartRun {
+    let now = Instant::now();
+    let result = f();
+    let duration = now.elapsed();
+    PartRun {
+        result,
+        duration,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solver_result_conversions() {
+        assert_eq!(SolverResult::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(SolverResult::from(u128::MAX).to_string(), "340282366920938463463374607431768211455");
+        assert_eq!(SolverResult::from(i128::MIN).to_string(), "-170141183460469231731687303715884105728");
+        assert_eq!(SolverResult::from(BigUint::from(7u32).pow(50)).to_string(), "1798465042647412146620280340569649349251249");
+    }
+
+    #[test]
+    fn test_solver_result_eq() {
+        assert_eq!(SolverResult::from(42u64), SolverResult::from(42));
+        assert_eq!(SolverResult::from(42usize), SolverResult::BigInt(BigInt::from(42)));
+        assert_ne!(SolverResult::from(-1), SolverResult::from(u64::MAX));
+        assert_ne!(SolverResult::from(42), SolverResult::from("42"));
+        assert_eq!(SolverResult::from_grid("#.\n.#"), SolverResult::Grid(vec!["#.".to_string(), ".#".to_string()]));
     }
 }
@@ -0,0 +1,152 @@
+use std::panic;
+use std::time::{Duration, Instant};
+
+use crate::solvers::{as_millis, json, InputSource, RunSolverError, SolverRun};
+use crate::solvers::registry::SolverInfo;
+
+const MAX_RESULT_WIDTH: usize = 24;
+
+pub struct BatchEntry {
+    pub info: &'static SolverInfo,
+    pub run: Result<SolverRun, RunSolverError>,
+}
+
+pub fn execute_batch(solvers: &[&'static SolverInfo], run_part_1: bool, run_part_2: bool) -> Vec<BatchEntry> {
+    solvers
+        .iter()
+        .map(|&info| BatchEntry {
+            info,
+            run: catch_unwind(|| info.execute(&InputSource::Default, run_part_1, run_part_2)),
+        })
+        .collect()
+}
+
+pub fn catch_unwind<R>(f: impl FnOnce() -> Result<R, RunSolverError>) -> Result<R, RunSolverError> {
+    // Failures are reported by the caller, silence the default panic message
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    panic::set_hook(hook);
+
+    match result {
+        Ok(result) => result,
+        Err(payload) => {
+            let msg = if let Some(msg) = payload.downcast_ref::<&str>() {
+                msg.to_string()
+            } else if let Some(msg) = payload.downcast_ref::<String>() {
+                msg.clone()
+            } else {
+                "unknown panic payload".to_string()
+            };
+            Err(RunSolverError::Panic(msg))
+        }
+    }
+}
+
+pub fn run_batch(solvers: &[&'static SolverInfo], run_part_1: bool, run_part_2: bool, quiet: bool, as_json: bool) -> usize {
+    let now = Instant::now();
+    let entries = execute_batch(solvers, run_part_1, run_part_2);
+    let total_duration = now.elapsed();
+
+    if as_json {
+        for entry in entries.iter() {
+            println!("{}", json::solver_run_to_json(entry.info, &entry.run));
+        }
+    } else {
+        print_summary(&entries, total_duration, quiet);
+    }
+
+    entries.iter()
+        .filter(|entry| entry.run.is_err())
+        .count()
+}
+
+pub fn print_summary(entries: &[BatchEntry], total_duration: Duration, quiet: bool) {
+    const HEADERS: [&str; 6] = ["Year", "Day", "Part 1", "Part 2", "Parse ms", "Parts ms"];
+
+    let rows: Vec<[String; 6]> = entries.iter()
+        .map(|entry| {
+            let year = entry.info.year().to_string();
+            let day = entry.info.day().to_string();
+            match &entry.run {
+                Ok(run) => {
+                    let parts_duration = run.part1.iter()
+                        .chain(run.part2.iter())
+                        .map(|part| part.duration)
+                        .sum();
+                    [
+                        year,
+                        day,
+                        format_result_cell(run.part1.as_ref().map(|part| part.result.to_string())),
+                        format_result_cell(run.part2.as_ref().map(|part| part.result.to_string())),
+                        format!("{:.3}", as_millis(run.parse_duration)),
+                        format!("{:.3}", as_millis(parts_duration)),
+                    ]
+                },
+                Err(_) => [year, day, "FAILED".to_string(), "-".to_string(), "-".to_string(), "-".to_string()],
+            }
+        })
+        .collect();
+
+    let mut widths = HEADERS.map(str::len);
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = usize::max(*width, cell.chars().count());
+        }
+    }
+
+    let print_row = |cells: [&str; 6]| {
+        let line = cells.iter()
+            .zip(widths.iter())
+            .enumerate()
+            .map(|(index, (cell, width))| {
+                // Text columns are left aligned, numeric ones right aligned
+                if index == 2 || index == 3 {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" | ");
+        println!("{line}");
+    };
+
+    print_row(HEADERS);
+    let separator = widths.map(|width| "-".repeat(width));
+    print_row(separator.each_ref().map(String::as_str));
+    for row in rows.iter() {
+        print_row(row.each_ref().map(String::as_str));
+    }
+
+    for entry in entries.iter() {
+        if let Err(err) = &entry.run {
+            eprintln!("{} day {}: {err}", entry.info.year(), entry.info.day());
+        }
+    }
+
+    if quiet {
+        return;
+    }
+
+    let failures = entries.iter().filter(|entry| entry.run.is_err()).count();
+    println!();
+    println!("{} solvers run, {} failed, total time {:.3} ms", entries.len(), failures, as_millis(total_duration));
+}
+
+fn format_result_cell(result: Option<String>) -> String {
+    let Some(result) = result else {
+        return "-".to_string();
+    };
+
+    if result.contains('\n') {
+        return format!("<{} lines>", result.trim_start_matches('\n').lines().count());
+    }
+
+    if result.chars().count() > MAX_RESULT_WIDTH {
+        let truncated: String = result.chars().take(MAX_RESULT_WIDTH - 1).collect();
+        return format!("{truncated}~");
+    }
+
+    result
+}
@@ -0,0 +1,269 @@
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::io::ErrorKind;
+use std::time::{Duration, Instant};
+
+use crate::solvers::{InputSource, ParseSolverError, RunSolverError, Solver};
+use crate::solvers::batch;
+use crate::solvers::registry::SolverInfo;
+use crate::parse_solver_error;
+
+pub struct BenchConfig {
+    pub runs: usize,
+    pub warmup: usize,
+    pub baseline: Option<String>,
+    pub save_baseline: Option<String>,
+    pub threshold: f64,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            runs: 10,
+            warmup: 1,
+            baseline: None,
+            save_baseline: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "cannot compute stats of an empty samples list");
+
+        let mut samples: Vec<f64> = samples.iter().map(|duration| duration.as_secs_f64() * 1000.0).collect();
+        samples.sort_by(f64::total_cmp);
+
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) * 0.5
+        } else {
+            samples[len / 2]
+        };
+        let mean = samples.iter().sum::<f64>() / len as f64;
+        let variance = samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / len as f64;
+
+        Stats {
+            min: samples[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl BenchResult {
+    fn stages(&self) -> impl Iterator<Item = (&'static str, Stats)> {
+        [("parse", Some(self.parse)), ("part1", self.part1), ("part2", self.part2)]
+            .into_iter()
+            .filter_map(|(stage, stats)| Some((stage, stats?)))
+    }
+}
+
+pub fn bench_solver<T: Solver>(input: &InputSource, run_part_1: bool, run_part_2: bool, config: &BenchConfig) -> Result<BenchResult, RunSolverError> {
+    let input = input.read::<T>()?;
+
+    let mut solver = None;
+    let parse = measure(config, || {
+        solver = Some(black_box(input.parse::<T>().map_err(RunSolverError::Parse)?));
+        Ok(())
+    })?;
+    let solver = solver.unwrap();
+
+    let part1 = if run_part_1 {
+        Some(measure(config, || { black_box(solver.run_part1()); Ok(()) })?)
+    } else {
+        None
+    };
+    let part2 = if run_part_2 {
+        Some(measure(config, || { black_box(solver.run_part2()); Ok(()) })?)
+    } else {
+        None
+    };
+
+    Ok(BenchResult {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+fn measure(config: &BenchConfig, mut f: impl FnMut() -> Result<(), RunSolverError>) -> Result<Stats, RunSolverError> {
+    for _ in 0..config.warmup {
+        f()?;
+    }
+
+    let mut samples = Vec::with_capacity(config.runs);
+    for _ in 0..config.runs.max(1) {
+        let now = Instant::now();
+        f()?;
+        samples.push(now.elapsed());
+    }
+    Ok(Stats::from_samples(&samples))
+}
+
+// Baseline files contain one `<year> <day> <stage> <median ms>` entry per line.
+pub struct Baseline {
+    medians: BTreeMap<(u32, u32, String), f64>,
+}
+
+impl Baseline {
+    pub fn load(path: &str) -> Result<Self, ParseSolverError> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => s.parse(),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Baseline { medians: BTreeMap::new() }),
+            Err(err) => Err(parse_solver_error!("fail to read baseline at path: {path} ({err})")),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        let mut s = String::new();
+        for ((year, day, stage), median) in self.medians.iter() {
+            s += &format!("{year} {day} {stage} {median:.6}\n");
+        }
+        std::fs::write(path, s)
+    }
+
+    pub fn get(&self, year: u32, day: u32, stage: &str) -> Option<f64> {
+        self.medians.get(&(year, day, stage.to_string())).copied()
+    }
+
+    pub fn set(&mut self, year: u32, day: u32, stage: &str, median: f64) {
+        self.medians.insert((year, day, stage.to_string()), median);
+    }
+}
+
+impl std::str::FromStr for Baseline {
+    type Err = ParseSolverError;
+
+    fn from_str(s: &str) -> Result<Self, ParseSolverError> {
+        let mut medians = BTreeMap::new();
+        for (index, line) in s.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let [year, day, stage, median] = line.split_whitespace().collect::<Vec<&str>>()[..] else {
+                return Err(parse_solver_error!("invalid baseline at line {}: {line}", index + 1));
+            };
+            medians.insert((year.parse()?, day.parse()?, stage.to_string()), median.parse()?);
+        }
+
+        Ok(Baseline { medians })
+    }
+}
+
+pub struct BenchSummary {
+    pub failures: usize,
+    pub regressions: usize,
+}
+
+impl BenchSummary {
+    pub fn is_success(&self) -> bool {
+        self.failures == 0 && self.regressions == 0
+    }
+}
+
+pub fn run_bench(solvers: &[&'static SolverInfo], input: &InputSource, run_part_1: bool, run_part_2: bool, config: &BenchConfig) -> Result<BenchSummary, ParseSolverError> {
+    let baseline = config.baseline.as_deref().map(Baseline::load).transpose()?;
+    let mut new_baseline = match config.save_baseline.as_deref() {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+
+    println!("{} runs, {} warm-up runs", config.runs.max(1), config.warmup);
+    print!("Year | Day | Stage | {:>10} | {:>10} | {:>10} | {:>10}", "Min ms", "Median ms", "Mean ms", "Stddev ms");
+    if baseline.is_some() {
+        print!(" | {:>10} | {:>8}", "Base ms", "Change");
+    }
+    println!();
+
+    let mut summary = BenchSummary {
+        failures: 0,
+        regressions: 0,
+    };
+    for info in solvers.iter() {
+        let (year, day) = (info.year(), info.day());
+        let result = match batch::catch_unwind(|| info.bench(input, run_part_1, run_part_2, config)) {
+            Ok(result) => result,
+            Err(err) => {
+                println!("{year} | {day:>3} | FAILED ({err})");
+                summary.failures += 1;
+                continue;
+            }
+        };
+
+        for (stage, stats) in result.stages() {
+            print!("{year} | {day:>3} | {stage:<5} | {:>10.3} | {:>10.3} | {:>10.3} | {:>10.3}", stats.min, stats.median, stats.mean, stats.stddev);
+            if let Some(baseline) = &baseline {
+                match baseline.get(year, day, stage) {
+                    Some(base) => {
+                        let change = if base > 0.0 { (stats.median - base) / base * 100.0 } else { 0.0 };
+                        print!(" | {base:>10.3} | {change:>+7.1}%");
+                        if change > config.threshold {
+                            print!(" REGRESSION");
+                            summary.regressions += 1;
+                        }
+                    },
+                    None => print!(" | {:>10} | {:>8}", "-", "-"),
+                }
+            }
+            println!();
+
+            if let Some(new_baseline) = &mut new_baseline {
+                new_baseline.set(year, day, stage, stats.median);
+            }
+        }
+    }
+
+    if let (Some(path), Some(new_baseline)) = (&config.save_baseline, &new_baseline) {
+        new_baseline.save(path)
+            .map_err(|err| parse_solver_error!("fail to write baseline at path: {path} ({err})"))?;
+        println!("Baseline saved to {path}");
+    }
+
+    if baseline.is_some() {
+        println!("{} regressions over {}% threshold", summary.regressions, config.threshold);
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.mean, 2.5);
+        assert!((stats.stddev - 1.25f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let baseline = Baseline::from_str("2023 4 parse 0.25\n2023 4 part1 1.5").unwrap();
+        assert_eq!(baseline.get(2023, 4, "parse"), Some(0.25));
+        assert_eq!(baseline.get(2023, 4, "part1"), Some(1.5));
+        assert_eq!(baseline.get(2023, 4, "part2"), None);
+        assert!(Baseline::from_str("2023 4 parse").is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use crate::solvers::{as_millis, ParseErrorKind, PartRun, RunSolverError, SolverResult, SolverRun};
+use crate::solvers::registry::SolverInfo;
+
+// One JSON object per solver, on a single line so batch runs produce JSON Lines:
+// {"year":2023,"day":4,"name":"Day04","parse_ms":0.1,"parts":[{"part":1,"type":"u64","value":13,"duration_ms":0.01}],"error":null}
+pub fn solver_run_to_json(info: &SolverInfo, run: &Result<SolverRun, RunSolverError>) -> String {
+    let mut fields = vec![
+        format!("\"year\":{}", info.year()),
+        format!("\"day\":{}", info.day()),
+        format!("\"name\":{}", escape(info.name())),
+    ];
+
+    match run {
+        Ok(run) => {
+            let parts: Vec<String> = [(1, &run.part1), (2, &run.part2)]
+                .into_iter()
+                .filter_map(|(part, part_run)| Some(part_run_to_json(part, part_run.as_ref()?)))
+                .collect();
+            fields.push(format!("\"parse_ms\":{}", millis(run.parse_duration)));
+            fields.push(format!("\"parts\":[{}]", parts.join(",")));
+            fields.push("\"error\":null".to_string());
+        },
+        Err(err) => {
+            fields.push("\"parse_ms\":null".to_string());
+            fields.push("\"parts\":[]".to_string());
+            fields.push(format!("\"error\":{}", error_to_json(err)));
+        },
+    }
+
+    format!("{{{}}}", fields.join(","))
+}
+
+fn part_run_to_json(part: u32, run: &PartRun) -> String {
+    format!(
+        "{{\"part\":{part},\"type\":{},\"value\":{},\"duration_ms\":{}}}",
+        escape(run.result.type_name()),
+        result_to_json(&run.result),
+        millis(run.duration),
+    )
+}
+
+// Most JSON readers store numbers as f64, so integers they can't hold exactly are written as strings.
+// An integer "value" is a number within +/-(2^53 - 1) and a string of its digits outside, whatever its
+// "type": readers should accept both. Strings are JSON strings, grids arrays of rows, invalid results null.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+fn result_to_json(result: &SolverResult) -> String {
+    match result {
+        SolverResult::Invalid => "null".to_string(),
+        SolverResult::I64(value) if value.unsigned_abs() <= MAX_SAFE_INTEGER => value.to_string(),
+        SolverResult::U64(value) if *value <= MAX_SAFE_INTEGER => value.to_string(),
+        SolverResult::I64(_) | SolverResult::U64(_) | SolverResult::U128(_) | SolverResult::BigInt(_) => escape(&result.to_string()),
+        SolverResult::String(value) => escape(value),
+        SolverResult::Grid(rows) => format!("[{}]", rows.iter().map(|row| escape(row)).collect::<Vec<String>>().join(",")),
+    }
+}
+
+fn error_to_json(err: &RunSolverError) -> String {
+    let kind = match err {
+        RunSolverError::ReadInput(..) => "read_input",
+        RunSolverError::MissingExample(..) => "missing_example",
+        RunSolverError::Parse(_) => "parse",
+        RunSolverError::Panic(_) => "panic",
+    };
+
+    let mut fields = vec![
+        format!("\"kind\":{}", escape(kind)),
+        format!("\"message\":{}", escape(&err.to_string())),
+    ];
+    if let RunSolverError::Parse(err) = err {
+        let parse_kind = match err.kind() {
+            ParseErrorKind::Other => "other",
+            ParseErrorKind::InvalidValue => "invalid_value",
+            ParseErrorKind::UnexpectedToken => "unexpected_token",
+            ParseErrorKind::UnexpectedEnd => "unexpected_end",
+            ParseErrorKind::TrailingInput => "trailing_input",
+        };
+        fields.push(format!("\"parse_kind\":{}", escape(parse_kind)));
+        fields.push(format!("\"line\":{}", optional(err.line())));
+        fields.push(format!("\"column\":{}", optional(err.column())));
+    }
+
+    format!("{{{}}}", fields.join(","))
+}
+
+fn optional(value: Option<usize>) -> String {
+    value.map_or("null".to_string(), |value| value.to_string())
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", as_millis(duration))
+}
+
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            '\r' => escaped += "\\r",
+            '\t' => escaped += "\\t",
+            c if (c as u32) < 0x20 => escaped += &format!("\\u{:04x}", c as u32),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::ParseSolverError;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("abc"), "\"abc\"");
+        assert_eq!(escape("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(result_to_json(&SolverResult::from(-42_i64)), "-42");
+        assert_eq!(result_to_json(&SolverResult::from(MAX_SAFE_INTEGER)), "9007199254740991");
+        assert_eq!(result_to_json(&SolverResult::from(MAX_SAFE_INTEGER + 1)), "\"9007199254740992\"");
+        assert_eq!(result_to_json(&SolverResult::from(i64::MIN)), "\"-9223372036854775808\"");
+        assert_eq!(result_to_json(&SolverResult::from(u128::MAX)), "\"340282366920938463463374607431768211455\"");
+        assert_eq!(result_to_json(&SolverResult::from_grid("#.\n.#")), "[\"#.\",\".#\"]");
+        assert_eq!(result_to_json(&SolverResult::Invalid), "null");
+
+        let run = PartRun {
+            result: SolverResult::from("abc"),
+            duration: Duration::from_micros(1500),
+        };
+        assert_eq!(part_run_to_json(2, &run), "{\"part\":2,\"type\":\"string\",\"value\":\"abc\",\"duration_ms\":1.500}");
+
+        let err = RunSolverError::Parse(ParseSolverError::with_kind(ParseErrorKind::InvalidValue, "bad").at(3, Some(5), "x"));
+        assert_eq!(
+            error_to_json(&err),
+            "{\"kind\":\"parse\",\"message\":\"Fail to create solver from input: line 3, column 5: invalid value: bad\\n    x\\n        ^\",\"parse_kind\":\"invalid_value\",\"line\":3,\"column\":5}",
+        );
+    }
+}
@@ -0,0 +1,387 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::solvers::{ParseErrorKind, ParseSolverError};
+
+// Parse each line of the input, errors are reported with the line number and content
+pub fn parse_lines<T>(s: &str, mut f: impl FnMut(&mut LineCursor) -> Result<T, ParseSolverError>) -> Result<Vec<T>, ParseSolverError> {
+    s.lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let mut cursor = LineCursor::new(index + 1, line);
+            f(&mut cursor).map_err(|err| err.at(index + 1, None, line))
+        })
+        .collect()
+}
+
+// Parse an input made of a single line
+pub fn parse_line<T>(s: &str, f: impl FnOnce(&mut LineCursor) -> Result<T, ParseSolverError>) -> Result<T, ParseSolverError> {
+    let mut lines = s.lines();
+    let line = lines.next().unwrap_or("");
+    if let Some(extra) = lines.find(|line| !line.is_empty()) {
+        return Err(ParseSolverError::with_kind(ParseErrorKind::TrailingInput, "expected a single line").at(2, None, extra));
+    }
+
+    let mut cursor = LineCursor::new(1, line);
+    f(&mut cursor).map_err(|err| err.at(1, None, line))
+}
+
+// Blocks of lines separated by empty lines
+pub fn sections(s: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut position = 0;
+    for (index, line) in s.split_inclusive('\n').enumerate() {
+        let is_empty = line.trim_end_matches(['\r', '\n']).is_empty();
+        match start {
+            None if !is_empty => start = Some((index + 1, position)),
+            Some((first_line, start_position)) if is_empty => {
+                sections.push(Section::new(first_line, &s[start_position..position]));
+                start = None;
+            },
+            _ => (),
+        }
+        position += line.len();
+    }
+    if let Some((first_line, start_position)) = start {
+        sections.push(Section::new(first_line, &s[start_position..]));
+    }
+    sections
+}
+
+pub struct Section<'a> {
+    first_line: usize,
+    text: &'a str,
+}
+
+#[allow(dead_code)]
+impl<'a> Section<'a> {
+    fn new(first_line: usize, text: &'a str) -> Self {
+        Section {
+            first_line,
+            text: text.trim_end_matches(['\r', '\n']),
+        }
+    }
+
+    pub fn first_line(&self) -> usize {
+        self.first_line
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    // Line numbers of the errors are made relative to the whole input
+    pub fn parse<T>(&self, f: impl FnOnce(&'a str) -> Result<T, ParseSolverError>) -> Result<T, ParseSolverError> {
+        f(self.text).map_err(|err| {
+            let first_line = self.text.lines().next().unwrap_or("");
+            err.offset_line(self.first_line - 1).at(self.first_line, None, first_line)
+        })
+    }
+
+    pub fn parse_lines<T>(&self, f: impl FnMut(&mut LineCursor) -> Result<T, ParseSolverError>) -> Result<Vec<T>, ParseSolverError> {
+        self.parse(|text| parse_lines(text, f))
+    }
+
+    pub fn parse_line<T>(&self, f: impl FnOnce(&mut LineCursor) -> Result<T, ParseSolverError>) -> Result<T, ParseSolverError> {
+        self.parse(|text| parse_line(text, f))
+    }
+}
+
+// Walks through a line of the input, errors are reported at the current column
+pub struct LineCursor<'a> {
+    number: usize,
+    line: &'a str,
+    position: usize,
+}
+
+#[allow(dead_code)]
+impl<'a> LineCursor<'a> {
+    pub fn new(number: usize, line: &'a str) -> Self {
+        LineCursor {
+            number,
+            line,
+            position: 0,
+        }
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn line(&self) -> &'a str {
+        self.line
+    }
+
+    // Byte offset in the line
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.position..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().trim().is_empty()
+    }
+
+    pub fn error(&self, kind: ParseErrorKind, msg: impl ToString) -> ParseSolverError {
+        self.error_at(self.position, kind, msg)
+    }
+
+    pub fn error_at(&self, position: usize, kind: ParseErrorKind, msg: impl ToString) -> ParseSolverError {
+        let column = self.line[..position].chars().count() + 1;
+        ParseSolverError::with_kind(kind, msg).at(self.number, Some(column), self.line)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    pub fn expect(&mut self, expected: &str) -> Result<(), ParseSolverError> {
+        if self.consume(expected) {
+            Ok(())
+        } else if self.is_empty() {
+            Err(self.error(ParseErrorKind::UnexpectedEnd, format!("expected `{expected}`")))
+        } else {
+            Err(self.error(ParseErrorKind::UnexpectedToken, format!("expected `{expected}`")))
+        }
+    }
+
+    // Consume the given text if it comes next
+    pub fn consume(&mut self, s: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(s) {
+            self.position += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.position += len;
+        &rest[..len]
+    }
+
+    // Next whitespace separated token
+    pub fn next_token(&mut self) -> Result<&'a str, ParseSolverError> {
+        self.skip_whitespace();
+        if self.is_empty() {
+            return Err(self.error(ParseErrorKind::UnexpectedEnd, "expected a value"));
+        }
+
+        let rest = self.rest();
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        self.position += len;
+        Ok(&rest[..len])
+    }
+
+    pub fn parse_next<T: FromStr>(&mut self) -> Result<T, ParseSolverError> where T::Err: Display {
+        self.skip_whitespace();
+        let position = self.position;
+        let token = self.next_token()?;
+        self.parse_at(position, token)
+    }
+
+    pub fn next_char(&mut self) -> Result<char, ParseSolverError> {
+        self.skip_whitespace();
+        let c = self.rest().chars().next().ok_or_else(|| self.error(ParseErrorKind::UnexpectedEnd, "expected a character"))?;
+        self.position += c.len_utf8();
+        Ok(c)
+    }
+
+    // Next character mapped by `f`, which returns None for invalid characters
+    pub fn parse_char<T>(&mut self, f: impl FnOnce(char) -> Option<T>) -> Result<T, ParseSolverError> {
+        self.skip_whitespace();
+        let position = self.position;
+        let c = self.next_char()?;
+        f(c).ok_or_else(|| self.error_at(position, ParseErrorKind::InvalidValue, format!("`{c}`")))
+    }
+
+    // Next token parsed by `f`, e.g. to use another radix
+    pub fn parse_next_with<T, E: Display>(&mut self, f: impl FnOnce(&str) -> Result<T, E>) -> Result<T, ParseSolverError> {
+        self.skip_whitespace();
+        let position = self.position;
+        let token = self.next_token()?;
+        f(token).map_err(|err| self.error_at(position, ParseErrorKind::InvalidValue, format!("`{token}` ({err})")))
+    }
+
+    // Text up to the delimiter, which is consumed
+    pub fn take_until(&mut self, delimiter: &str) -> Result<&'a str, ParseSolverError> {
+        let rest = self.rest();
+        let Some(len) = rest.find(delimiter) else {
+            return Err(self.error_at(self.line.len(), ParseErrorKind::UnexpectedEnd, format!("expected `{delimiter}`")));
+        };
+        self.position += len + delimiter.len();
+        Ok(rest[..len].trim())
+    }
+
+    pub fn parse_until<T: FromStr>(&mut self, delimiter: &str) -> Result<T, ParseSolverError> where T::Err: Display {
+        self.skip_whitespace();
+        let position = self.position;
+        let token = self.take_until(delimiter)?;
+        self.parse_at(position, token)
+    }
+
+    // Whitespace separated values up to the delimiter, which is consumed
+    pub fn parse_list_until<T: FromStr>(&mut self, delimiter: &str) -> Result<Vec<T>, ParseSolverError> where T::Err: Display {
+        let end = match self.rest().find(delimiter) {
+            Some(len) => self.position + len,
+            None => return Err(self.error_at(self.line.len(), ParseErrorKind::UnexpectedEnd, format!("expected `{delimiter}`"))),
+        };
+
+        let mut values = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.position >= end {
+                break;
+            }
+
+            // The delimiter may touch the last value
+            let position = self.position;
+            let rest = &self.line[position..end];
+            let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            self.position += len;
+            values.push(self.parse_at(position, &rest[..len])?);
+        }
+        self.position = end + delimiter.len();
+        Ok(values)
+    }
+
+    // Whitespace separated values up to the end of the line
+    pub fn parse_list<T: FromStr>(&mut self) -> Result<Vec<T>, ParseSolverError> where T::Err: Display {
+        let mut values = Vec::new();
+        while !self.is_empty() {
+            values.push(self.parse_next()?);
+        }
+        Ok(values)
+    }
+
+    // Values separated by the separator up to the end of the line
+    pub fn parse_separated<T: FromStr>(&mut self, separator: &str) -> Result<Vec<T>, ParseSolverError> where T::Err: Display {
+        let mut values = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let position = self.position;
+            let rest = self.rest();
+            let len = rest.find(separator).unwrap_or(rest.len());
+            self.position += len;
+            values.push(self.parse_at(position, rest[..len].trim_end())?);
+            if !self.consume(separator) {
+                return Ok(values);
+            }
+        }
+    }
+
+    pub fn finish(&mut self) -> Result<(), ParseSolverError> {
+        self.skip_whitespace();
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::TrailingInput, format!("unexpected `{}`", self.rest())))
+        }
+    }
+
+    fn parse_at<T: FromStr>(&self, position: usize, token: &str) -> Result<T, ParseSolverError> where T::Err: Display {
+        token.parse().map_err(|err| self.error_at(position, ParseErrorKind::InvalidValue, format!("`{token}` ({err})")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let values = parse_lines("a: 1 2 | 3\nb: 4 | 5 6\nc: 7 8|9", |line| {
+            let name = line.take_until(":")?.to_string();
+            let left = line.parse_list_until::<u32>("|")?;
+            let right = line.parse_list::<u32>()?;
+            Ok((name, left, right))
+        }).unwrap();
+        assert_eq!(values, vec![
+            ("a".to_string(), vec![1, 2], vec![3]),
+            ("b".to_string(), vec![4], vec![5, 6]),
+            ("c".to_string(), vec![7, 8], vec![9]),
+        ]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_lines("1 2\n3 x4", |line| line.parse_list::<u32>()).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidValue);
+        assert_eq!((err.line(), err.column()), (Some(2), Some(3)));
+        assert_eq!(err.snippet(), Some("3 x4"));
+        assert_eq!(err.to_string(), "line 2, column 3: invalid value: `x4` (invalid digit found in string)\n    3 x4\n      ^");
+
+        let err = parse_lines("Card 1", |line| {
+            line.expect("Card")?;
+            line.take_until(":").map(str::to_string)
+        }).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnexpectedEnd);
+        assert_eq!(err.column(), Some(7));
+
+        let err = parse_lines("Game 1", |line| line.expect("Card")).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnexpectedToken);
+        assert_eq!(err.column(), Some(1));
+
+        let err = parse_lines("1 2", |line| { line.parse_next::<u32>()?; line.finish() }).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::TrailingInput);
+
+        // Errors without a location get the line attached
+        let err = parse_lines("ok\nfail", |line| {
+            if line.line() == "fail" { Err(ParseSolverError::new("failure")) } else { Ok(()) }
+        }).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(2), None));
+        assert_eq!(err.to_string(), "line 2: failure\n    fail");
+    }
+
+    #[test]
+    fn test_sections() {
+        let s = "1,2\n\nx y\nz w\n\n\n3 4\n";
+        let sections = sections(s);
+        assert_eq!(sections.iter().map(|section| (section.first_line(), section.text())).collect::<Vec<_>>(), vec![
+            (1, "1,2"),
+            (3, "x y\nz w"),
+            (7, "3 4"),
+        ]);
+
+        assert_eq!(sections[0].parse_line(|line| line.parse_separated::<u32>(",")).unwrap(), vec![1, 2]);
+
+        // Line numbers are relative to the whole input
+        let err = sections[1].parse_lines(|line| line.parse_list::<u32>()).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(3), Some(1)));
+        let err = sections[2].parse_lines(|line| { line.parse_next::<u32>()?; line.expect(",") }).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(7), Some(3)));
+    }
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line("3, 4,5\n", |line| line.parse_separated::<u32>(",")).unwrap(), vec![3, 4, 5]);
+
+        let err = parse_line("3,x", |line| line.parse_separated::<u32>(",")).unwrap_err();
+        assert_eq!((err.kind(), err.line(), err.column()), (ParseErrorKind::InvalidValue, Some(1), Some(3)));
+
+        let err = parse_line("1\n2", |line| line.parse_next::<u32>()).unwrap_err();
+        assert_eq!((err.kind(), err.line()), (ParseErrorKind::TrailingInput, Some(2)));
+    }
+
+    #[test]
+    fn test_parse_char() {
+        let digit = |c: char| c.to_digit(10);
+        let values = parse_line("1 2x", |line| Ok((line.parse_char(digit)?, line.parse_char(digit)?))).unwrap();
+        assert_eq!(values, (1, 2));
+
+        let err = parse_line("1 2x", |line| { line.parse_char(digit)?; line.parse_char(digit)?; line.parse_char(digit) }).unwrap_err();
+        assert_eq!((err.kind(), err.column()), (ParseErrorKind::InvalidValue, Some(4)));
+
+        let err = parse_line("1", |line| { line.parse_char(digit)?; line.parse_char(digit) }).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnexpectedEnd);
+    }
+}
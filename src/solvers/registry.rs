@@ -0,0 +1,157 @@
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+
+use crate::solvers::{self, InputSource, RunSolverError, Solver, SolverRun};
+use crate::solvers::bench::{self, BenchConfig, BenchResult};
+use crate::utils::graph::GraphFormat;
+
+pub struct SolverInfo {
+    year: u32,
+    day: u32,
+    name: &'static str,
+    input_path: &'static str,
+    execute: fn(&InputSource, bool, bool) -> Result<SolverRun, RunSolverError>,
+    bench: fn(&InputSource, bool, bool, &BenchConfig) -> Result<BenchResult, RunSolverError>,
+    export_graph: fn(&InputSource, GraphFormat) -> Result<Option<String>, RunSolverError>,
+}
+
+#[allow(dead_code)]
+impl SolverInfo {
+    pub fn new<T: Solver>() -> Self {
+        let (year, day) = parse_input_path(T::INPUT_PATH)
+            .unwrap_or_else(|| panic!("invalid input path (= {}), expected `inputs/YYYY/DD.txt`", T::INPUT_PATH));
+        let name = std::any::type_name::<T>()
+            .rsplit("::")
+            .next()
+            .unwrap();
+
+        SolverInfo {
+            year,
+            day,
+            name,
+            input_path: T::INPUT_PATH,
+            execute: solvers::execute_solver::<T>,
+            bench: bench::bench_solver::<T>,
+            export_graph: solvers::export_solver_graph::<T>,
+        }
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn input_path(&self) -> &'static str {
+        self.input_path
+    }
+
+    pub fn execute(&self, input: &InputSource, run_part_1: bool, run_part_2: bool) -> Result<SolverRun, RunSolverError> {
+        (self.execute)(input, run_part_1, run_part_2)
+    }
+
+    pub fn bench(&self, input: &InputSource, run_part_1: bool, run_part_2: bool, config: &BenchConfig) -> Result<BenchResult, RunSolverError> {
+        (self.bench)(input, run_part_1, run_part_2, config)
+    }
+
+    pub fn export_graph(&self, input: &InputSource, format: GraphFormat) -> Result<Option<String>, RunSolverError> {
+        (self.export_graph)(input, format)
+    }
+}
+
+fn parse_input_path(path: &str) -> Option<(u32, u32)> {
+    let mut split = path.strip_prefix("inputs/")?.split('/');
+    let year = split.next()?.parse().ok()?;
+    let day = split.next()?.strip_suffix(".txt")?.parse().ok()?;
+    if split.next().is_some() {
+        return None;
+    }
+    Some((year, day))
+}
+
+pub fn all() -> &'static [SolverInfo] {
+    static REGISTRY: OnceLock<Vec<SolverInfo>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut solvers = solvers::registered_solvers();
+        solvers.sort_by_key(|info| (info.year, info.day));
+        solvers
+    })
+}
+
+#[allow(dead_code)]
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<u32> = all().iter().map(|info| info.year).collect();
+    years.dedup();
+    years
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static SolverInfo> {
+    all().iter().find(|info| info.year == year && info.day == day)
+}
+
+#[allow(dead_code)]
+pub fn find_year(year: u32) -> impl Iterator<Item = &'static SolverInfo> {
+    all().iter().filter(move |info| info.year == year)
+}
+
+pub fn select(year: Option<u32>, days: Option<RangeInclusive<u32>>) -> Vec<&'static SolverInfo> {
+    all()
+        .iter()
+        .filter(|info| year.is_none_or(|year| info.year == year))
+        .filter(|info| days.as_ref().is_none_or(|days| days.contains(&info.day)))
+        .collect()
+}
+
+#[macro_export]
+macro_rules! register_solvers {
+    ($($module:ident :: $solver:ident),* $(,)?) => {
+        $(mod $module;)*
+
+        pub fn solvers() -> Vec<$crate::solvers::registry::SolverInfo> {
+            vec![$($crate::solvers::registry::SolverInfo::new::<$module::$solver>()),*]
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! register_years {
+    ($($module:ident),* $(,)?) => {
+        $(mod $module;)*
+
+        fn registered_solvers() -> Vec<$crate::solvers::registry::SolverInfo> {
+            let mut solvers = Vec::new();
+            $(solvers.extend($module::solvers());)*
+            solvers
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input_path() {
+        assert_eq!(parse_input_path("inputs/2023/04.txt"), Some((2023, 4)));
+        assert_eq!(parse_input_path("inputs/2020/1.txt"), Some((2020, 1)));
+        assert_eq!(parse_input_path("inputs/20XX/00.txt"), None);
+        assert_eq!(parse_input_path("inputs/2023/04.txt/05.txt"), None);
+    }
+
+    #[test]
+    fn test_registry() {
+        let solvers = all();
+        assert!(!solvers.is_empty());
+        for pair in solvers.windows(2) {
+            assert!((pair[0].year, pair[0].day) < (pair[1].year, pair[1].day), "duplicated or unsorted solver: {} day {}", pair[1].year, pair[1].day);
+        }
+        assert_eq!(find(2023, 4).map(SolverInfo::name), Some("Day04"));
+        assert_eq!(find_year(2021).count(), 25);
+    }
+}
@@ -0,0 +1,141 @@
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("day_template.txt");
+
+pub struct Scaffold {
+    pub created: Vec<PathBuf>,
+    pub modified: Vec<PathBuf>,
+}
+
+pub fn create_day(root: &Path, year: u32, day: u32) -> Result<Scaffold> {
+    let mut scaffold = Scaffold {
+        created: Vec::new(),
+        modified: Vec::new(),
+    };
+
+    let solvers_path = root.join("src/solvers.rs");
+    let year_path = root.join(format!("src/solvers/year_{year}.rs"));
+    let input_path = format!("inputs/{year}/{day:02}.txt");
+
+    // Register the year module if needed
+    if !year_path.exists() {
+        fs::write(&year_path, "crate::register_solvers! {\n}\n")?;
+        scaffold.created.push(year_path.clone());
+
+        let solvers = fs::read_to_string(&solvers_path)?;
+        let solvers = insert_macro_entry(&solvers, "register_years", &format!("year_{year}"))?;
+        fs::write(&solvers_path, solvers)?;
+        scaffold.modified.push(solvers_path);
+    }
+
+    // Older years don't zero-pad their day modules, follow the convention of the year module
+    let year_module = fs::read_to_string(&year_path)?;
+    let (module, name) = if year_module.contains("day_1::") {
+        (format!("day_{day}"), format!("Day{day}"))
+    } else {
+        (format!("day_{day:02}"), format!("Day{day:02}"))
+    };
+
+    let day_path = root.join(format!("src/solvers/year_{year}/{module}.rs"));
+    if day_path.exists() {
+        return Err(Error::new(ErrorKind::AlreadyExists, format!("day module already exists: {}", day_path.display())));
+    }
+
+    let source = DAY_TEMPLATE
+        .replace("Day00", &name)
+        .replace("inputs/20XX/00.txt", &input_path);
+    fs::create_dir_all(day_path.parent().unwrap())?;
+    fs::write(&day_path, source)?;
+    scaffold.created.push(day_path);
+
+    let year_module = insert_macro_entry(&year_module, "register_solvers", &format!("{module}::{name}"))?;
+    fs::write(&year_path, year_module)?;
+    if !scaffold.created.contains(&year_path) {
+        scaffold.modified.push(year_path);
+    }
+
+    let input_path = root.join(input_path);
+    if !input_path.exists() {
+        fs::create_dir_all(input_path.parent().unwrap())?;
+        fs::write(&input_path, "")?;
+        scaffold.created.push(input_path);
+    }
+
+    Ok(scaffold)
+}
+
+fn insert_macro_entry(source: &str, macro_name: &str, entry: &str) -> Result<String> {
+    let invalid_data = || Error::new(ErrorKind::InvalidData, format!("fail to find the `{macro_name}!` invocation"));
+
+    let header = format!("crate::{macro_name}! {{\n");
+    let start = source.find(&header).ok_or_else(invalid_data)? + header.len();
+    let end = start + source[start..].find('}').ok_or_else(invalid_data)?;
+
+    let mut entries: Vec<String> = source[start..end]
+        .lines()
+        .map(|line| line.trim().trim_end_matches(',').to_string())
+        .filter(|line| !line.is_empty())
+        .collect();
+    if entries.iter().any(|existing| existing == entry) {
+        return Err(Error::new(ErrorKind::AlreadyExists, format!("`{entry}` is already registered")));
+    }
+    entries.push(entry.to_string());
+    entries.sort_by_key(|entry| sort_key(entry));
+
+    let mut result = source[..start].to_string();
+    for entry in entries.iter() {
+        result += &format!("    {entry},\n");
+    }
+    result += &source[end..];
+    Ok(result)
+}
+
+// Sort entries by the number in their module name, so `day_9` comes before `day_10`
+fn sort_key(entry: &str) -> (u32, String) {
+    let module = entry.split("::").next().unwrap();
+    let number = module
+        .rsplit('_')
+        .next()
+        .and_then(|number| number.parse().ok())
+        .unwrap_or(u32::MAX);
+    (number, entry.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_macro_entry() {
+        let source = "crate::register_solvers! {\n    day_1::Day1,\n    day_10::Day10,\n}\n";
+        let source = insert_macro_entry(source, "register_solvers", "day_9::Day9").unwrap();
+        assert_eq!(source, "crate::register_solvers! {\n    day_1::Day1,\n    day_9::Day9,\n    day_10::Day10,\n}\n");
+        assert!(insert_macro_entry(&source, "register_solvers", "day_9::Day9").is_err());
+        assert!(insert_macro_entry(&source, "register_years", "year_2022").is_err());
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = std::env::temp_dir().join(format!("advent_of_code_scaffold_{}", std::process::id()));
+        fs::create_dir_all(root.join("src/solvers")).unwrap();
+        fs::write(root.join("src/solvers.rs"), "crate::register_years! {\n    year_2021,\n    year_2023,\n}\n").unwrap();
+
+        let scaffold = create_day(&root, 2022, 3).unwrap();
+        assert_eq!(scaffold.created.len(), 3);
+
+        let solvers = fs::read_to_string(root.join("src/solvers.rs")).unwrap();
+        assert_eq!(solvers, "crate::register_years! {\n    year_2021,\n    year_2022,\n    year_2023,\n}\n");
+        let year_module = fs::read_to_string(root.join("src/solvers/year_2022.rs")).unwrap();
+        assert_eq!(year_module, "crate::register_solvers! {\n    day_03::Day03,\n}\n");
+        let day_module = fs::read_to_string(root.join("src/solvers/year_2022/day_03.rs")).unwrap();
+        assert!(day_module.contains("pub struct Day03"));
+        assert!(day_module.contains("\"inputs/2022/03.txt\""));
+        assert!(root.join("inputs/2022/03.txt").exists());
+
+        assert!(create_day(&root, 2022, 3).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
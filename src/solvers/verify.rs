@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::io::ErrorKind;
+
+use crate::solvers::{ParseSolverError, PartRun};
+use crate::solvers::batch::{self, BatchEntry};
+use crate::solvers::registry::SolverInfo;
+use crate::parse_solver_error;
+
+// Answers files contain one `<day> <part> <answer>` entry per line, multi-line answers have
+// their line breaks escaped as `\n`. Empty lines and lines starting with `#` are ignored.
+pub struct Answers {
+    answers: HashMap<(u32, u32), String>,
+}
+
+impl Answers {
+    pub fn path(year: u32) -> String {
+        format!("inputs/{year}/answers.txt")
+    }
+
+    pub fn load(year: u32) -> Result<Self, ParseSolverError> {
+        let path = Answers::path(year);
+        match std::fs::read_to_string(&path) {
+            Ok(s) => s.parse(),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Answers { answers: HashMap::new() }),
+            Err(err) => Err(parse_solver_error!("fail to read answers at path: {path} ({err})")),
+        }
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = ParseSolverError;
+
+    fn from_str(s: &str) -> Result<Self, ParseSolverError> {
+        let mut answers = HashMap::new();
+        for (index, line) in s.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut split = line.splitn(3, ' ');
+            let (Some(day), Some(part), Some(answer)) = (split.next(), split.next(), split.next()) else {
+                return Err(parse_solver_error!("invalid answer at line {}: {line}", index + 1));
+            };
+            let day = day.parse()?;
+            let part = part.parse()?;
+            if part != 1 && part != 2 {
+                return Err(parse_solver_error!("invalid part (= {part}) at line {}", index + 1));
+            }
+            if answers.insert((day, part), answer.replace("\\n", "\n")).is_some() {
+                return Err(parse_solver_error!("duplicated answer for day {day} part {part} at line {}", index + 1));
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+}
+
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+impl Verdict {
+    fn new(part: Option<&PartRun>, expected: Option<&str>) -> Option<Self> {
+        let part = part?;
+        let Some(expected) = expected else {
+            return Some(Verdict::Missing);
+        };
+
+        let actual = part.result.to_string();
+        if actual == expected {
+            Some(Verdict::Pass)
+        } else {
+            Some(Verdict::Fail(actual))
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct VerifySummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+}
+
+impl VerifySummary {
+    pub fn is_success(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+pub fn run_verify(solvers: &[&'static SolverInfo], run_part_1: bool, run_part_2: bool, quiet: bool) -> VerifySummary {
+    let entries = batch::execute_batch(solvers, run_part_1, run_part_2);
+
+    let mut summary = VerifySummary::default();
+    let mut answers: HashMap<u32, Result<Answers, ParseSolverError>> = HashMap::new();
+    for entry in entries.iter() {
+        let year = entry.info.year();
+        let answers = answers.entry(year).or_insert_with(|| Answers::load(year));
+        match answers {
+            Ok(answers) => verify_entry(entry, answers, quiet, &mut summary),
+            Err(err) => {
+                println!("{year} day {:>2}: FAIL ({err})", entry.info.day());
+                summary.failed += 1;
+            }
+        }
+    }
+
+    println!();
+    println!("{} passed, {} failed, {} missing", summary.passed, summary.failed, summary.missing);
+    summary
+}
+
+fn verify_entry(entry: &BatchEntry, answers: &Answers, quiet: bool, summary: &mut VerifySummary) {
+    let year = entry.info.year();
+    let day = entry.info.day();
+    let run = match &entry.run {
+        Ok(run) => run,
+        Err(err) => {
+            println!("{year} day {day:>2}: FAIL ({err})");
+            summary.failed += 1;
+            return;
+        }
+    };
+
+    for (part, part_run) in [(1, run.part1.as_ref()), (2, run.part2.as_ref())] {
+        let expected = answers.get(day, part);
+        let Some(verdict) = Verdict::new(part_run, expected) else {
+            continue;
+        };
+
+        match verdict {
+            Verdict::Pass => {
+                if !quiet {
+                    println!("{year} day {day:>2} part {part}: PASS");
+                }
+                summary.passed += 1;
+            },
+            Verdict::Fail(actual) => {
+                println!("{year} day {day:>2} part {part}: FAIL (expected: {}, actual: {actual})", expected.unwrap());
+                summary.failed += 1;
+            },
+            Verdict::Missing => {
+                if !quiet {
+                    println!("{year} day {day:>2} part {part}: MISSING");
+                }
+                summary.missing += 1;
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use indoc::indoc;
+
+    static TEST_ANSWERS: &str = indoc!{
+       "# day part answer
+        1 1 365619
+        1 2 236873508
+
+        13 2 \\n#..#\\n.##."
+    };
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::from_str(TEST_ANSWERS).unwrap();
+        assert_eq!(answers.get(1, 1), Some("365619"));
+        assert_eq!(answers.get(1, 2), Some("236873508"));
+        assert_eq!(answers.get(13, 2), Some("\n#..#\n.##."));
+        assert_eq!(answers.get(2, 1), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::from_str("1 3 42").is_err());
+        assert!(Answers::from_str("1 1").is_err());
+        assert!(Answers::from_str("1 1 42\n1 1 43").is_err());
+    }
+}
@@ -8,12 +8,11 @@ impl FromStr for Day1 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let lines = s.lines();
-        let mut values = Vec::with_capacity(200);
-        for line in lines {
-            let value = line.parse().unwrap_or_default();
-            values.push(value);
-        }
+        let values = parse_lines(s, |line| {
+            let value = line.parse_next()?;
+            line.finish()?;
+            Ok(value)
+        })?;
 
         Ok(Day1 { values })
     }
@@ -21,6 +20,7 @@ impl FromStr for Day1 {
 
 impl Solver for Day1 {
     const INPUT_PATH: &'static str = "inputs/2020/01.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
     
     fn run_part1(&self) -> SolverResult {
         let length = self.values.len();
@@ -56,22 +56,21 @@ impl Solver for Day1 {
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    1721
+    979
+    366
+    299
+    675
+    1456
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            1721
-            979
-            366
-            299
-            675
-            1456
-        "};
-
         let day = Day1::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 514579.into(), "Part1");
         assert_eq!(day.run_part2(), 241861950.into(), "Part2");
@@ -1,37 +1,42 @@
 use crate::solvers::prelude::*;
 
-use itertools::Itertools;
-
 pub struct Day2 {
-    lines: Vec<String>
+    entries: Vec<Entry>
 }
 
 impl FromStr for Day2 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let lines = s.lines().map_into().collect_vec();
-        Ok(Day2 { lines })
+        let entries = parse_lines(s, |line| {
+            let policy_first = line.parse_until("-")?;
+            let policy_second = line.parse_next()?;
+            let policy_char = line.next_char()?;
+            line.expect(":")?;
+            let password = line.next_token()?.to_string();
+            line.finish()?;
+
+            Ok(Entry {
+                policy_first,
+                policy_second,
+                policy_char,
+                password,
+            })
+        })?;
+
+        Ok(Day2 { entries })
     }
 }
 
 impl Solver for Day2 {
     const INPUT_PATH: &'static str = "inputs/2020/02.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut valid_passwords = 0;
-        for line in &self.lines {
-            let split = line.split(' ');
-            let tokens: Vec<&str> = split.collect();
-            let tokens_min_max: Vec<&str> = tokens[0].split('-').collect();
-            
-            let policy_first: usize = tokens_min_max[0].parse().unwrap_or_default();
-            let policy_second: usize = tokens_min_max[1].parse().unwrap_or_default();
-            let policy_char: char = tokens[1].chars().next().unwrap_or_default();
-            let password = tokens[2];
-            
-            let char_count = password.matches(policy_char).count();
-            if char_count >= policy_first && char_count <= policy_second {
+        for entry in &self.entries {
+            let char_count = entry.password.matches(entry.policy_char).count();
+            if char_count >= entry.policy_first && char_count <= entry.policy_second {
                 valid_passwords += 1;
             }
         }
@@ -41,19 +46,12 @@ impl Solver for Day2 {
 
     fn run_part2(&self) -> SolverResult {
         let mut valid_passwords = 0;
-        for line in self.lines.iter() {
-            let split = line.split(' ');
-            let tokens: Vec<&str> = split.collect();
-            let tokens_min_max: Vec<&str> = tokens[0].split('-').collect();
-            
-            let policy_first: usize = tokens_min_max[0].parse().unwrap_or_default();
-            let policy_second: usize = tokens_min_max[1].parse().unwrap_or_default();
-            let policy_char: char = tokens[1].chars().next().unwrap_or_default();
-            let password = tokens[2];
-            
-            let first_char: char = password.chars().nth(policy_first - 1).unwrap_or_default();
-            let second_char: char = password.chars().nth(policy_second - 1).unwrap_or_default();
-            if (first_char == policy_char) != (second_char == policy_char) {
+        for entry in self.entries.iter() {
+            // Positions start at 1
+            let char_at = |position: usize| position.checked_sub(1).and_then(|index| entry.password.chars().nth(index));
+            let first_char = char_at(entry.policy_first);
+            let second_char = char_at(entry.policy_second);
+            if (first_char == Some(entry.policy_char)) != (second_char == Some(entry.policy_char)) {
                 valid_passwords += 1;
             }
         }
@@ -62,19 +60,25 @@ impl Solver for Day2 {
     }
 }
 
+struct Entry {
+    policy_first: usize,
+    policy_second: usize,
+    policy_char: char,
+    password: String,
+}
+
+static TEST_INPUT: &str = indoc!{"
+    1-3 a: abcde
+    1-3 b: cdefg
+    2-9 c: ccccccccc
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            1-3 a: abcde
-            1-3 b: cdefg
-            2-9 c: ccccccccc
-        "};
-
         let day = Day2::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 2.into(), "Part1");
         assert_eq!(day.run_part2(), 1.into(), "Part2");
@@ -17,6 +17,7 @@ impl FromStr for Day3 {
 
 impl Solver for Day3 {
     const INPUT_PATH: &'static str = "inputs/2020/03.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
     
     fn run_part1(&self) -> SolverResult {
         self.compute_slope(3, 1).into()
@@ -61,27 +62,26 @@ impl Day3 {
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    ..##.......
+    #...#...#..
+    .#....#..#.
+    ..#.#...#.#
+    .#...##..#.
+    ..#.##.....
+    .#.#.#....#
+    .#........#
+    #.##...#...
+    #...##....#
+    .#..#...#.#
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            ..##.......
-            #...#...#..
-            .#....#..#.
-            ..#.#...#.#
-            .#...##..#.
-            ..#.##.....
-            .#.#.#....#
-            .#........#
-            #.##...#...
-            #...##....#
-            .#..#...#.#
-        "};
-
         let day = Day3::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 7.into(), "Part1");
         assert_eq!(day.run_part2(), 336.into(), "Part2");
@@ -34,6 +34,7 @@ impl FromStr for Day5 {
 
 impl Solver for Day5 {
     const INPUT_PATH: &'static str = "inputs/2020/05.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT_1A, TEST_INPUT_1B];
 
     fn run_part1(&self) -> SolverResult {
         for index in (0..self.seats.len()).rev() {
@@ -56,23 +57,22 @@ impl Solver for Day5 {
     }
 }
 
+static TEST_INPUT_1A: &str = indoc!{"
+    FBFBBFFRLR
+"};
+
+static TEST_INPUT_1B: &str = indoc!{"
+    BFFFBBFRRR
+    FFFBBBFRRR
+    BBFFBBFRLL
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT_1A: &str = indoc!{"
-            FBFBBFFRLR
-        "};
-
-        static TEST_INPUT_1B: &str = indoc!{"
-            BFFFBBFRRR
-            FFFBBBFRRR
-            BBFFBBFRLL
-        "};
-
         let day = Day5::from_str(TEST_INPUT_1A).unwrap();
         assert_eq!(day.run_part1(), 357.into(), "Part1A");
 
@@ -3,36 +3,31 @@ use crate::solvers::prelude::*;
 use itertools::Itertools;
 
 pub struct Day6 {
-    groups: Vec<String>
+    // Answers of each person as a bit per letter
+    groups: Vec<Vec<u32>>
 }
 
 impl FromStr for Day6 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let pat = if s.contains('\r') { "\r\n\r\n" } else { "\n\n" };
-        let groups = s.split(pat).map_into().collect_vec(); 
+        let groups = sections(s)
+            .iter()
+            .map(|section| section.parse_lines(parse_answers))
+            .try_collect()?;
         Ok(Day6 { groups })
     }
 }
 
 impl Solver for Day6 {
     const INPUT_PATH: &'static str = "inputs/2020/06.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT_1A, TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut result = 0;
         for group in &self.groups {
-            let mut bits: u32 = 0;
-            for line in group.lines() {
-                for letter in line.chars() {
-                    let offset = (letter as u32) - ('a' as u32);
-                    let mask = 1 << offset;
-                    if (bits & mask) == 0 {
-                        result += 1;
-                        bits |= mask;
-                    }
-                }
-            }
+            let group_bits = group.iter().fold(0, |acc, person_bits| acc | person_bits);
+            result += group_bits.count_ones();
         }
 
         result.into()
@@ -41,17 +36,7 @@ impl Solver for Day6 {
     fn run_part2(&self) -> SolverResult {
         let mut result = 0;
         for group in &self.groups {
-            let mut group_bits: u32 = u32::MAX;
-            for line in group.lines() {
-                let mut person_bits: u32 = 0;
-                for letter in line.chars() {
-                    let offset = (letter as u32) - ('a' as u32);
-                    person_bits |= 1 << offset;
-                }
-    
-                group_bits &= person_bits;
-            }
-    
+            let group_bits = group.iter().fold(u32::MAX, |acc, person_bits| acc & person_bits);
             result += group_bits.count_ones();
         }
     
@@ -59,37 +44,45 @@ impl Solver for Day6 {
     }
 }
 
+fn parse_answers(line: &mut LineCursor) -> Result<u32, ParseSolverError> {
+    let mut person_bits: u32 = 0;
+    while !line.is_empty() {
+        let offset = line.parse_char(|letter| letter.is_ascii_lowercase().then(|| u32::from(letter) - u32::from('a')))?;
+        person_bits |= 1 << offset;
+    }
+    Ok(person_bits)
+}
+
+static TEST_INPUT_1A: &str = indoc!{"
+    abcx
+    abcy
+    abcz
+"};
+
+static TEST_INPUT: &str = indoc!{"
+    abc
+
+    a
+    b
+    c
+    
+    ab
+    ac
+    
+    a
+    a
+    a
+    a
+    
+    b
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT_1A: &str = indoc!{"
-            abcx
-            abcy
-            abcz
-        "};
-
-        static TEST_INPUT: &str = indoc!{"
-            abc
-
-            a
-            b
-            c
-            
-            ab
-            ac
-            
-            a
-            a
-            a
-            a
-            
-            b
-        "};
-        
         let day = Day6::from_str(TEST_INPUT_1A).unwrap();
         assert_eq!(day.run_part1(), 6.into(), "Part1");
 
@@ -2,8 +2,6 @@ use std::collections::HashMap;
 
 use crate::solvers::prelude::*;
 
-use regex::Regex;
-
 pub struct Day7 {
     bag_repository: BagRepository
 }
@@ -13,13 +11,14 @@ impl FromStr for Day7 {
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
         Ok(Day7 {
-            bag_repository: BagRepository::from(s)
+            bag_repository: BagRepository::from(s)?
         })
     }
 }
 
 impl Solver for Day7 {
     const INPUT_PATH: &'static str = "inputs/2020/07.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT, TEST_INPUT_2B];
 
     fn run_part1(&self) -> SolverResult {
         match self.bag_repository.color_to_index.get("shiny gold") {
@@ -65,44 +64,38 @@ struct BagRepository {
 }
 
 impl BagRepository {
-    fn from(input: &str) -> BagRepository{
-        let regex_bag = Regex::new(r"(.*) bags contain (.*).").unwrap();
-        let regex_content = Regex::new(r" ?([0-9]*) (.*) bag[s]?(.*)").unwrap();
-
-        let lines: Vec<&str> = input.lines().collect();
-        
+    fn from(input: &str) -> Result<BagRepository, ParseSolverError> {
         let mut bag_repository = BagRepository {
-            bags: Vec::with_capacity(lines.len()),
-            color_to_index: HashMap::with_capacity(lines.len()),
+            bags: Vec::new(),
+            color_to_index: HashMap::new(),
         };
 
-        for line in lines {
-            let captures = regex_bag.captures(line).unwrap();
-            let bag_color = captures.get(1).map_or("", |m| m.as_str());
-            let bag_contents = captures.get(2).map_or("", |m| m.as_str()).split(',');
-            
+        parse_lines(input, |line| {
+            let bag_color = line.take_until("bags contain")?;
             let index = bag_repository.get_or_create_bag(bag_color);
-            
-            for bag_content in bag_contents {
-                if !regex_content.is_match(bag_content) {
-                    break;
-                }
-
-                let captures = regex_content.captures(bag_content).unwrap();
-                let content_count = captures.get(1).map_or("", |m| m.as_str()).parse().unwrap_or_default();
-                let content_color = captures.get(2).map_or("", |m| m.as_str());
 
-                let content_index = bag_repository.get_or_create_bag(content_color);
-
-                let bag = &mut bag_repository.bags[index];
-                bag.contents.push(Content {
-                    bag_index: content_index,
-                    count: content_count,
-                });
+            if !line.consume("no other bags.") {
+                loop {
+                    let content_count = line.parse_next()?;
+                    let content_color = line.take_until("bag")?;
+                    line.consume("s");
+
+                    let content_index = bag_repository.get_or_create_bag(content_color);
+                    bag_repository.bags[index].contents.push(Content {
+                        bag_index: content_index,
+                        count: content_count,
+                    });
+
+                    if !line.consume(",") {
+                        line.expect(".")?;
+                        break;
+                    }
+                }
             }
-        }
+            line.finish()
+        })?;
 
-        bag_repository
+        Ok(bag_repository)
     }
 
     fn get_or_create_bag(&mut self, color: &str) -> usize {
@@ -145,35 +138,34 @@ impl BagRepository {
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    light red bags contain 1 bright white bag, 2 muted yellow bags.
+    dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+    bright white bags contain 1 shiny gold bag.
+    muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+    shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+    dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+    vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+    faded blue bags contain no other bags.
+    dotted black bags contain no other bags.
+"};
+
+static TEST_INPUT_2B: &str = indoc!{"
+    shiny gold bags contain 2 dark red bags.
+    dark red bags contain 2 dark orange bags.
+    dark orange bags contain 2 dark yellow bags.
+    dark yellow bags contain 2 dark green bags.
+    dark green bags contain 2 dark blue bags.
+    dark blue bags contain 2 dark violet bags.
+    dark violet bags contain no other bags.
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            light red bags contain 1 bright white bag, 2 muted yellow bags.
-            dark orange bags contain 3 bright white bags, 4 muted yellow bags.
-            bright white bags contain 1 shiny gold bag.
-            muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
-            shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
-            dark olive bags contain 3 faded blue bags, 4 dotted black bags.
-            vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
-            faded blue bags contain no other bags.
-            dotted black bags contain no other bags.
-        "};
-
-        static TEST_INPUT_2B: &str = indoc!{"
-            shiny gold bags contain 2 dark red bags.
-            dark red bags contain 2 dark orange bags.
-            dark orange bags contain 2 dark yellow bags.
-            dark yellow bags contain 2 dark green bags.
-            dark green bags contain 2 dark blue bags.
-            dark blue bags contain 2 dark violet bags.
-            dark violet bags contain no other bags.
-        "};
-        
         let day = Day7::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 4.into(), "Part1");
         assert_eq!(day.run_part2(), 32.into(), "Part2");
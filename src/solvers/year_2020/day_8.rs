@@ -1,7 +1,5 @@
 use crate::solvers::prelude::*;
 
-use regex::Regex;
-
 pub struct Day8 {
     instructions: Box<[Instruction]>
 }
@@ -10,13 +8,14 @@ impl FromStr for Day8 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let instructions = compile_input(s).into_boxed_slice();
+        let instructions = compile_input(s)?.into_boxed_slice();
         Ok(Day8 { instructions })
     }
 }
 
 impl Solver for Day8 {
     const INPUT_PATH: &'static str = "inputs/2020/08.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
 
@@ -158,53 +157,47 @@ enum ProgramResults {
     InstructionOutOfRange,
 }
 
-fn compile_input(input: &str) -> Vec<Instruction> {
-    let regex = Regex::new(r"(\w{3}) ([+|-]\d+)").unwrap();
-
-    let mut instructions: Vec<Instruction> = Vec::new();
-    for line in input.lines() {
-        let captures = regex.captures(line).unwrap();
-        let op_code = captures.get(1).map_or("", |m| m.as_str());
-        let argument = captures.get(2).map_or("", |m| m.as_str()).parse().unwrap();
+fn compile_input(input: &str) -> Result<Vec<Instruction>, ParseSolverError> {
+    parse_lines(input, |line| {
+        let op_code = line.parse_next_with(str_to_op_code)?;
+        let argument = line.parse_next()?;
+        line.finish()?;
 
-        instructions.push(Instruction {
-            op_code: str_to_op_code(op_code),
+        Ok(Instruction {
+            op_code,
             argument,
-        });
-    }
-
-    instructions
+        })
+    })
 }
 
-fn str_to_op_code(s: &str) -> OpCodes
+fn str_to_op_code(s: &str) -> Result<OpCodes, ParseSolverError>
 {
     match s {
-        "nop" => OpCodes::Nop,
-        "acc" => OpCodes::Acc,
-        "jmp" => OpCodes::Jmp,
-        _ => panic!("Invalid OpCode: {s}"),
+        "nop" => Ok(OpCodes::Nop),
+        "acc" => Ok(OpCodes::Acc),
+        "jmp" => Ok(OpCodes::Jmp),
+        _ => Err(parse_solver_error!("invalid op code")),
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    nop +0
+    acc +1
+    jmp +4
+    acc +3
+    jmp -3
+    acc -99
+    acc +1
+    jmp -4
+    acc +6
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            nop +0
-            acc +1
-            jmp +4
-            acc +3
-            jmp -3
-            acc -99
-            acc +1
-            jmp -4
-            acc +6
-        "};
-
         let day = Day8::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 5.into(), "Part1");
         assert_eq!(day.run_part2(), 8.into(), "Part2");
@@ -8,13 +8,18 @@ impl FromStr for Day1 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let values = s.lines().map(|value| value.parse().unwrap()).collect();
+        let values = parse_lines(s, |line| {
+            let value = line.parse_next()?;
+            line.finish()?;
+            Ok(value)
+        })?;
         Ok(Day1 { values })
     }
 }
 
 impl Solver for Day1 {
     const INPUT_PATH: &'static str = "inputs/2021/01.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut count = 0;
@@ -45,26 +50,25 @@ impl Solver for Day1 {
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    199
+    200
+    208
+    210
+    200
+    207
+    240
+    269
+    260
+    263
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            199
-            200
-            208
-            210
-            200
-            207
-            240
-            269
-            260
-            263
-        "};
-
         let day = Day1::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 7.into(), "Part1");
         assert_eq!(day.run_part2(), 5.into(), "Part2");
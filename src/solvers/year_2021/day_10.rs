@@ -8,27 +8,26 @@ impl FromStr for Day10 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut lines = Vec::new();
-        for line in s.lines() {
+        let lines = parse_lines(s, |line| {
             let mut tokens = Vec::new();
-            for c in line.chars() {
-                let token = match c {
-                    '(' => Token { brackets_type: BracketsType::Round, open: true },
-                    ')' => Token { brackets_type: BracketsType::Round, open: false },
-                    '[' => Token { brackets_type: BracketsType::Square, open: true },
-                    ']' => Token { brackets_type: BracketsType::Square, open: false },
-                    '{' => Token { brackets_type: BracketsType::Curly, open: true },
-                    '}' => Token { brackets_type: BracketsType::Curly, open: false },
-                    '<' => Token { brackets_type: BracketsType::Angle, open: true },
-                    '>' => Token { brackets_type: BracketsType::Angle, open: false },
-                    _ => panic!("invalid character: {c}"),
-                };
+            while !line.is_empty() {
+                let token = line.parse_char(|c| match c {
+                    '(' => Some(Token { brackets_type: BracketsType::Round, open: true }),
+                    ')' => Some(Token { brackets_type: BracketsType::Round, open: false }),
+                    '[' => Some(Token { brackets_type: BracketsType::Square, open: true }),
+                    ']' => Some(Token { brackets_type: BracketsType::Square, open: false }),
+                    '{' => Some(Token { brackets_type: BracketsType::Curly, open: true }),
+                    '}' => Some(Token { brackets_type: BracketsType::Curly, open: false }),
+                    '<' => Some(Token { brackets_type: BracketsType::Angle, open: true }),
+                    '>' => Some(Token { brackets_type: BracketsType::Angle, open: false }),
+                    _ => None,
+                })?;
 
                 tokens.push(token);
             }
 
-            lines.push(tokens);
-        }
+            Ok(tokens)
+        })?;
 
         Ok(Day10 { lines })
     }
@@ -36,6 +35,7 @@ impl FromStr for Day10 {
 
 impl Solver for Day10 {
     const INPUT_PATH: &'static str = "inputs/2021/10.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut score = 0;
@@ -109,26 +109,25 @@ enum BracketsType {
     Angle,
 }
 
+static TEST_INPUT: &str = indoc!{"
+    [({(<(())[]>[[{[]{<()<>>
+    [(()[<>])]({[<{<<[]>>(
+    {([(<{}[<>[]}>{[]{[(<()>
+    (((({<>}<{<{<>}{[]{[]{}
+    [[<[([]))<([[{}[[()]]]
+    [{[{({}]{}}([{[{{{}}([]
+    {<[[]]>}<{[{[{[]{()[[[]
+    [<(<(<(<{}))><([]([]()
+    <{([([[(<>()){}]>(<<{{
+    <{([{{}}[<[[[<>{}]]]>[]]
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            [({(<(())[]>[[{[]{<()<>>
-            [(()[<>])]({[<{<<[]>>(
-            {([(<{}[<>[]}>{[]{[(<()>
-            (((({<>}<{<{<>}{[]{[]{}
-            [[<[([]))<([[{}[[()]]]
-            [{[{({}]{}}([{[{{{}}([]
-            {<[[]]>}<{[{[{[]{()[[[]
-            [<(<(<(<{}))><([]([]()
-            <{([([[(<>()){}]>(<<{{
-            <{([{{}}[<[[[<>{}]]]>[]]
-        "};
-
         let day = Day10::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 26397.into(), "Part1");
         assert_eq!(day.run_part2(), 288957.into(), "Part2");
@@ -1,34 +1,19 @@
 use crate::solvers::prelude::*;
-
-const GRID_SIZE: usize = 10;
-const GRID_CAPACITY: usize = GRID_SIZE * GRID_SIZE;
-
-static NEIGHBOUR_DIR: [(isize, isize); 8] = [
-    (-1, -1),
-    (-1,  0),
-    (-1,  1),
-    ( 0, -1),
-    ( 0,  1),
-    ( 1, -1),
-    ( 1,  0),
-    ( 1,  1),
-];
+use crate::utils::{Array2D, Point2D};
 
 pub struct Day11 {
-    grid: [usize; GRID_CAPACITY]
+    grid: Array2D<usize>
 }
 
 impl FromStr for Day11 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut grid= [0; GRID_CAPACITY];
-        for (y, line) in s.lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                let index = get_index(x, y);
-                grid[index] = (c as usize) - ('0' as usize);
-            }
-        }
+        let grid = Array2D::from_str_map(s, false, |_, c| {
+            c.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or_else(|| ParseSolverError::with_kind(ParseErrorKind::InvalidValue, format!("invalid energy level: {c}")))
+        })?;
 
         Ok(Day11 { grid })
     }
@@ -36,14 +21,15 @@ impl FromStr for Day11 {
 
 impl Solver for Day11 {
     const INPUT_PATH: &'static str = "inputs/2021/11.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
-        let mut grid = self.grid;
+        let mut grid = self.grid.clone();
         let mut flashes_count = 0;
         for _ in 0..100 {
-            for y in 0..GRID_SIZE {
-                for x in 0..GRID_SIZE {
-                    incremente(&mut grid, x, y);
+            for y in 0..grid.height() {
+                for x in 0..grid.width() {
+                    incremente(&mut grid, Point2D::new(x, y));
                 }
             }
 
@@ -59,14 +45,14 @@ impl Solver for Day11 {
     }
 
     fn run_part2(&self) -> SolverResult {
-        let mut grid = self.grid;
+        let mut grid = self.grid.clone();
         let mut step = 0;
         loop {
             step += 1;
 
-            for y in 0..GRID_SIZE {
-                for x in 0..GRID_SIZE {
-                    incremente(&mut grid, x, y);
+            for y in 0..grid.height() {
+                for x in 0..grid.width() {
+                    incremente(&mut grid, Point2D::new(x, y));
                 }
             }
 
@@ -88,45 +74,37 @@ impl Solver for Day11 {
     }
 }
 
-fn get_index(x: usize, y: usize) -> usize {
-    x + y * GRID_SIZE
-}
-
-fn incremente(grid: &mut [usize], x: usize, y: usize) {
-    let index = get_index(x, y);
-    grid[index] += 1;
+fn incremente(grid: &mut Array2D<usize>, coords: Point2D) {
+    grid[coords] += 1;
 
-    if grid[index] == 10 {
-        for dir in &NEIGHBOUR_DIR {
-            let x = x.wrapping_add_signed(dir.0);
-            let y = y.wrapping_add_signed(dir.1);
-            if x < GRID_SIZE && y < GRID_SIZE {
-                incremente(grid, x, y);
+    if grid[coords] == 10 {
+        for neighbour in coords.moore_neighbours() {
+            if grid.is_in_bound(neighbour) {
+                incremente(grid, neighbour);
             }
         }
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    5483143223
+    2745854711
+    5264556173
+    6141336146
+    6357385478
+    4167524645
+    2176841721
+    6882881134
+    4846848554
+    5283751526
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            5483143223
-            2745854711
-            5264556173
-            6141336146
-            6357385478
-            4167524645
-            2176841721
-            6882881134
-            4846848554
-            5283751526
-        "};
-
         let day = Day11::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 1656.into(), "Part1");
         assert_eq!(day.run_part2(), 195.into(), "Part2");
@@ -1,6 +1,5 @@
-use std::collections::HashMap;
-
 use crate::solvers::prelude::*;
+use crate::utils::graph::{AdjacencyFormat, LabeledGraph};
 
 pub struct Day12 {
     nodes: Box<[Node]>,
@@ -12,60 +11,30 @@ impl FromStr for Day12 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut nodes = vec![
-        Node::new(false, true), 
-        Node::new(false, false)];
-    
-        let mut node_indices = HashMap::new();
-        node_indices.insert("start", 0);
-        node_indices.insert("end", 1);
-        
-        for line in s.lines() {
-            let mut splits = line.split('-');
-            let left = splits.next().unwrap();
-            let right = splits.next().unwrap();
-
-            let left_index = {
-                match node_indices.get(left) {
-                    Some(index) => *index,
-                    None => {
-                        let index = nodes.len();
-                        nodes.push(Node::new(is_big(left), false));
-                        node_indices.insert(left, index);
-                        index
-                    }
-                }
-            };
-
-            let right_index = {
-                match node_indices.get(right) {
-                    Some(index) => *index,
-                    None => {
-                        let index = nodes.len();
-                        nodes.push(Node::new(is_big(right), false));
-                        node_indices.insert(right, index);
-                        index
-                    }
-                }
-            };
-
-            let left_node = &mut nodes[left_index];
-            left_node.connections.push(right_index);
-
-            let right_node = &mut nodes[right_index];
-            right_node.connections.push(left_index);
-        }
+        let graph = LabeledGraph::<()>::parse_adjacency(s, AdjacencyFormat::Dash, true)?;
+        let start = graph.node_id("start").ok_or(parse_solver_error!("missing start cave"))?;
+        let end = graph.node_id("end").ok_or(parse_solver_error!("missing end cave"))?;
+
+        let nodes = graph.graph()
+            .nodes_iter()
+            .map(|node| Node {
+                connections: node.connections().iter().map(|connection| connection.to_node_id()).collect(),
+                is_big: is_big(node.value()),
+                is_start: node.id() == start,
+            })
+            .collect();
 
         Ok(Day12 {
-            nodes: nodes.into_boxed_slice(),
-            start: node_indices["start"],
-            end: node_indices["end"],
+            nodes,
+            start,
+            end,
         })
     }
 }
 
 impl Solver for Day12 {
     const INPUT_PATH: &'static str = "inputs/2021/12.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT, TEST_INPUT_1B];
 
     fn run_part1(&self) -> SolverResult {
         let node_count = self.nodes.len();
@@ -96,12 +65,6 @@ struct Node {
     is_start: bool
 }
 
-impl Node {
-    fn new(is_big: bool, is_start: bool) -> Node {
-        Node { connections: Vec::new(), is_big, is_start }
-    }
-}
-
 fn is_big(s: &str) -> bool {
     s.chars().next().unwrap().is_uppercase()
 }
@@ -142,36 +105,35 @@ fn count_paths(nodes: &[Node], visit_counts: &mut [usize], current: usize, end:
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    start-A
+    start-b
+    A-c
+    A-b
+    b-d
+    A-end
+    b-end
+"};
+
+static TEST_INPUT_1B: &str = indoc!{"
+    dc-end
+    HN-start
+    start-kj
+    dc-start
+    dc-HN
+    LN-dc
+    HN-end
+    kj-sa
+    kj-HN
+    kj-dc
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            start-A
-            start-b
-            A-c
-            A-b
-            b-d
-            A-end
-            b-end
-        "};
-
-        static TEST_INPUT_1B: &str = indoc!{"
-            dc-end
-            HN-start
-            start-kj
-            dc-start
-            dc-HN
-            LN-dc
-            HN-end
-            kj-sa
-            kj-HN
-            kj-dc
-        "};
-
         let day = Day12::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 10.into(), "Part1");
         assert_eq!(day.run_part2(), 36.into(), "Part2");
@@ -1,7 +1,5 @@
 use crate::solvers::prelude::*;
 
-use regex::Regex;
-
 pub struct Day13 {
     dots: Vec<(usize, usize)>,
     fold_instructions: Vec<FoldInstruction>,
@@ -11,38 +9,33 @@ impl FromStr for Day13 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let pat = if s.contains('\r') { "\r\n\r\n" } else { "\n\n" };
-        let mut splits = s.split(pat);
-
-        let lines = splits.next().unwrap().lines();
-        let mut dots= Vec::new();
-        for line in lines {
-            let mut splits = line.split(',');
-            let x = splits.next().unwrap().parse().unwrap();
-            let y = splits.next().unwrap().parse().unwrap();
-            dots.push((x, y));
-        }
-
-        let regex = Regex::new(r"fold along (x|y)=([0-9]*)").unwrap();
-        let mut fold_instructions = Vec::new();
-        for line in splits.next().unwrap().lines() {
-            let captures = regex.captures(line).unwrap();
-
-            let axis = captures.get(1).unwrap().as_str().chars().next().unwrap();
-            let axis = match axis {
-                'x' => Axis::X,
-                'y' => Axis::Y,
-                _ => panic!("invalid char: {axis}")
-            };
-
-            let offset = captures.get(2).unwrap().as_str();
-            let offset = offset.parse().unwrap();
-
-            fold_instructions.push(FoldInstruction {
+        let [dots_section, fold_section] = &sections(s)[..] else {
+            return Err(parse_solver_error!("expected dots and fold instructions separated by an empty line"));
+        };
+
+        let dots = dots_section.parse_lines(|line| {
+            let x = line.parse_until(",")?;
+            let y = line.parse_next()?;
+            line.finish()?;
+            Ok((x, y))
+        })?;
+
+        let fold_instructions = fold_section.parse_lines(|line| {
+            line.expect("fold along")?;
+            let axis = line.parse_char(|c| match c {
+                'x' => Some(Axis::X),
+                'y' => Some(Axis::Y),
+                _ => None,
+            })?;
+            line.expect("=")?;
+            let offset = line.parse_next()?;
+            line.finish()?;
+
+            Ok(FoldInstruction {
                 axis,
                 offset,
-            });
-        }
+            })
+        })?;
 
         Ok(Day13 { dots, fold_instructions })
     }
@@ -50,6 +43,7 @@ impl FromStr for Day13 {
 
 impl Solver for Day13 {
     const INPUT_PATH: &'static str = "inputs/2021/13.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut pixels = Vec::new();
@@ -68,17 +62,18 @@ impl Solver for Day13 {
         let mut dot_count = 0;
         self.process_fold(false, &mut pixels, &mut width, &mut heigth, &mut dot_count);
 
-        let mut result = String::new();
+        let mut rows = Vec::with_capacity(heigth);
         for y in 0..heigth {
-            result.push('\n');
+            let mut row = String::with_capacity(width);
             for x in 0..width {
                 let index = x + y * width;
                 let c = if pixels[index] { '#' } else { '.' };
-                result.push(c);
+                row.push(c);
             }
+            rows.push(row);
         }
 
-        result.into()
+        SolverResult::Grid(rows)
     }
 }
 
@@ -145,39 +140,37 @@ enum Axis {
     Y,
 }
 
+static TEST_INPUT: &str = indoc!{"
+    6,10
+    0,14
+    9,10
+    0,3
+    10,4
+    4,11
+    6,0
+    6,12
+    4,1
+    0,13
+    10,12
+    3,4
+    3,0
+    8,4
+    1,10
+    2,14
+    8,10
+    9,0
+    
+    fold along y=7
+    fold along x=5
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            6,10
-            0,14
-            9,10
-            0,3
-            10,4
-            4,11
-            6,0
-            6,12
-            4,1
-            0,13
-            10,12
-            3,4
-            3,0
-            8,4
-            1,10
-            2,14
-            8,10
-            9,0
-            
-            fold along y=7
-            fold along x=5
-        "};
-
         static TEST_PART2_RESULT: &str = indoc!{"
-        
             #####
             #...#
             #...#
@@ -186,6 +179,6 @@ mod tests {
 
         let day = Day13::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 17.into(), "Part1");
-        assert_eq!(day.run_part2(), TEST_PART2_RESULT.into(), "Part2");
+        assert_eq!(day.run_part2(), SolverResult::from_grid(TEST_PART2_RESULT), "Part2");
     }
 }
\ No newline at end of file
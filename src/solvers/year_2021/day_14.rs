@@ -9,15 +9,39 @@ impl FromStr for Day14 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
+        let [template_section, insertion_pairs_section] = &sections(s)[..] else {
+            return Err(parse_solver_error!("expected a template and insertion pairs separated by an empty line"));
+        };
+
+        let template: Vec<char> = template_section.parse_line(|line| {
+            let template = line.take_while(|c| c.is_ascii_uppercase());
+            line.finish()?;
+            if template.is_empty() {
+                return Err(line.error(ParseErrorKind::UnexpectedEnd, "expected a template"));
+            }
+            Ok(template.chars().collect())
+        })?;
+
+        let insertion_pairs = insertion_pairs_section.parse_lines(|line| {
+            let element = |c: char| c.is_ascii_uppercase().then_some(c);
+            let left = line.parse_char(element)?;
+            let right = line.parse_char(element)?;
+            line.expect("->")?;
+            let result = line.parse_char(element)?;
+            line.finish()?;
+            Ok((left, right, result))
+        })?;
+
         Ok(Day14 {
-            naive: naive::Data::from_input(s),
-            opti: opti::Data::from_input(s),
+            naive: naive::Data::new(&template, &insertion_pairs),
+            opti: opti::Data::new(&template, &insertion_pairs),
         })
     }
 }
 
 impl Solver for Day14 {
     const INPUT_PATH: &'static str = "inputs/2021/14.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         self.naive.simulate(10).into()
@@ -35,34 +59,28 @@ mod naive {
     }
 
     impl Data {
-        pub fn from_input(input: &str) -> Data {
-            let pat = if input.contains('\r') { "\r\n\r\n" } else { "\n\n" };
-            let mut splits = input.split(pat);
-
-            // Parse template
+        pub fn new(elements: &[char], rules: &[(char, char, char)]) -> Data {
             let mut template = Vec::new();
             let mut previous = None;
-            for (index, c) in splits.next().unwrap().chars().enumerate() {
+            for (index, c) in elements.iter().enumerate() {
                 template.push(Node {
-                    element: c,
+                    element: *c,
                     previous,
                     next: Some(index + 1)
                 });
                 previous = Some(index);
             }
-            template.last_mut().unwrap().next = None;
-        
-            // Parse insertion pairs
-            let mut insertion_pairs = Vec::new();
-            for line in splits.next().unwrap().lines() {
-                let mut chars = line.chars();
-        
-                insertion_pairs.push(InsertionPair {
-                    left: chars.next().unwrap(),
-                    right: chars.next().unwrap(),
-                    result: chars.nth(4).unwrap(),
-                });
+            if let Some(last) = template.last_mut() {
+                last.next = None;
             }
+
+            let insertion_pairs = rules.iter()
+                .map(|(left, right, result)| InsertionPair {
+                    left: *left,
+                    right: *right,
+                    result: *result,
+                })
+                .collect();
         
             Data {
                 template,
@@ -145,7 +163,6 @@ mod naive {
 
 mod opti {
     use std::collections::HashMap;
-    use regex::Regex;
 
     pub struct Data {
         insertion_pairs: Vec<InsertionPair>,
@@ -153,24 +170,13 @@ mod opti {
     }
 
     impl Data {
-        pub fn from_input(input: &str) -> Data {
-            let pat = if input.contains('\r') { "\r\n\r\n" } else { "\n\n" };
-            let mut splits = input.split(pat);
-            let template = splits.next().unwrap();
-
-            // Parse insertion pairs
-            let regex = Regex::new(r"([A-Z]{2}) -> ([A-Z])").unwrap();
+        // Elements must be upper case letters
+        pub fn new(template: &[char], rules: &[(char, char, char)]) -> Data {
             let mut insertion_pairs= Vec::new();
             let mut insertion_pair_keys: Vec<(char, char)> = Vec::new();
             let mut insertion_pair_results: Vec<char> = Vec::new();
             let mut insertion_pair_indices: HashMap<(char, char), usize>  = HashMap::new();
-            for line in splits.next().unwrap().lines() {
-                let captures = regex.captures(line).unwrap();
-                let mut elements = captures.get(1).unwrap().as_str().chars();
-                let left_element = elements.next().unwrap();
-                let right_element = elements.next().unwrap();
-                let result = captures.get(2).unwrap().as_str().chars().next().unwrap();
-
+            for &(left_element, right_element, result) in rules {
                 let key = (left_element, right_element);
                 let index = insertion_pairs.len();
                 insertion_pair_indices.insert(key, index);
@@ -202,7 +208,7 @@ mod opti {
 
             // Parse template
             let mut previous = None;
-            for c in template.chars() {
+            for &c in template {
                 let index = (c as usize) - ('A' as usize);
                 element_sums[index] += 1;
 
@@ -274,34 +280,33 @@ mod opti {
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    NNCB
+
+    CH -> B
+    HH -> N
+    CB -> H
+    NH -> C
+    HB -> C
+    HC -> B
+    HN -> C
+    NN -> C
+    BH -> H
+    NC -> B
+    NB -> B
+    BN -> B
+    BB -> N
+    BC -> B
+    CC -> N
+    CN -> C
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            NNCB
-
-            CH -> B
-            HH -> N
-            CB -> H
-            NH -> C
-            HB -> C
-            HC -> B
-            HN -> C
-            NN -> C
-            BH -> H
-            NC -> B
-            NB -> B
-            BN -> B
-            BB -> N
-            BC -> B
-            CC -> N
-            CN -> C
-        "};
-
         let day = Day14::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 1588.into(), "Part1");
         assert_eq!(day.run_part2(), 2188189693529_i64.into(), "Part2");
@@ -1,7 +1,6 @@
-use std::collections::BinaryHeap;
-use std::cmp::Ordering;
-
 use crate::solvers::prelude::*;
+use crate::utils::Array2D;
+use crate::utils::search;
 
 static NEIGHBOUR_DIRS: [(isize, isize); 4] = [
     (-1,  0),
@@ -19,7 +18,7 @@ impl FromStr for Day15 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let map_part1 = Map::from_input(s);
+        let map_part1 = Map::from_input(s)?;
         let map_part2 = Map::from_other_map(&map_part1);
         debug_assert_eq!(map_part1.nodes[0].cost, map_part2.nodes[0].cost);
 
@@ -29,6 +28,7 @@ impl FromStr for Day15 {
 
 impl Solver for Day15 {
     const INPUT_PATH: &'static str = "inputs/2021/15.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         self.map_part1.dijkstra().into()
@@ -46,9 +46,14 @@ struct Map {
 }
 
 impl Map {
-    fn from_input(input: &str) -> Map {
-        let width = input.lines().next().unwrap().len();
-        let heigth = input.lines().count();
+    fn from_input(input: &str) -> Result<Map, ParseSolverError> {
+        let costs = Array2D::from_str_map(input, false, |_, c| {
+            c.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or_else(|| ParseSolverError::with_kind(ParseErrorKind::InvalidValue, format!("invalid risk level: {c}")))
+        })?;
+        let width = costs.width() as usize;
+        let heigth = costs.height() as usize;
         let capacity = width * heigth;
 
         let mut map = Map {
@@ -57,15 +62,14 @@ impl Map {
             heigth,
         };
 
-        for (y, line) in input.lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
+        for y in 0..heigth {
+            for x in 0..width {
                 let index = map.get_index(x, y);
-                let cost = (c as usize) - ('0' as usize);
-                map.nodes[index] = Node::new(x, y, width, heigth, cost);
+                map.nodes[index] = Node::new(x, y, width, heigth, costs[[x as i64, y as i64]]);
             }
         }
 
-        map
+        Ok(map)
     }
 
     fn from_other_map(other_map: &Map) -> Map {
@@ -103,92 +107,26 @@ impl Map {
         x + y * self.width
     }
 
-    fn dijkstra(&self) -> i64 {
-        let mut path_nodes = vec![PathNode::default(); self.nodes.len()];
-        let last_index = self.nodes.len() - 1;
-
-        path_nodes[0].distance = 0;
-
-        let mut open_set: BinaryHeap<OpenNode> = BinaryHeap::new();
-        open_set.push(OpenNode {
-            index: 0,
-            heuristic: 0,
-        });
-
-        while let Some(open_node) = open_set.pop() {
-            let current_index = open_node.index;
-            let current_distance = path_nodes[current_index].distance;
-            if current_index == last_index {
-                return current_distance as i64;
-            }
-            
-            let current_node = &self.nodes[current_index];
-            for i in 0..current_node.neighbours_count {
-                let neighbour_index = current_node.neighbours[i];
-                let neighbour_path = &mut path_nodes[neighbour_index];
-                if neighbour_path.visited {
-                    continue;
-                }
-
-                let transition_cost = self.nodes[neighbour_index].cost;
-                let new_distance = current_distance + transition_cost;
-                if new_distance < neighbour_path.distance {
-                    neighbour_path.distance = new_distance;
-                    open_set.push(OpenNode {
-                        index: neighbour_index,
-                        heuristic: neighbour_path.distance,
-                    });
-                }
-            }
-
-            path_nodes[current_index].visited = true;
-        }
-
-        panic!("path not found")
+    fn successors(&self, index: usize) -> impl Iterator<Item = (usize, u64)> + '_ {
+        let node = &self.nodes[index];
+        node.neighbours[..node.neighbours_count]
+            .iter()
+            .map(|neighbour_index| (*neighbour_index, self.nodes[*neighbour_index].cost as u64))
     }
 
-    fn astar(&self) -> i64 {
-        let mut path_nodes = vec![PathNode::default(); self.nodes.len()];
+    fn dijkstra(&self) -> u64 {
         let last_index = self.nodes.len() - 1;
+        search::dijkstra(0, |index| self.successors(*index), |index| *index == last_index)
+            .expect("path not found")
+            .cost
+    }
 
-        path_nodes[0].distance = 0;
-
-        let mut open_set: BinaryHeap<OpenNode> = BinaryHeap::new();
-        open_set.push(OpenNode {
-            index: 0,
-            heuristic: 0,
-        });
-
-        while let Some(open_node) = open_set.pop() {
-            let current_index = open_node.index;
-            let current_distance = path_nodes[current_index].distance;
-            if current_index == last_index {
-                return current_distance as i64;
-            }
-            
-            let current_node = &self.nodes[current_index];
-            for i in 0..current_node.neighbours_count {
-                let neighbour_index = current_node.neighbours[i];
-                let neighbour_path = &mut path_nodes[neighbour_index];
-                if neighbour_path.visited {
-                    continue;
-                }
-
-                let neighbour_node = &self.nodes[neighbour_index];
-                let new_distance = current_distance + neighbour_node.cost;
-                if new_distance < neighbour_path.distance {
-                    neighbour_path.distance = new_distance;
-                    open_set.push(OpenNode {
-                        index: neighbour_index,
-                        heuristic: neighbour_path.distance + neighbour_node.min_remaining_distance,
-                    });
-                }
-            }
-
-            path_nodes[current_index].visited = true;
-        }
-
-        panic!("path not found")
+    fn astar(&self) -> u64 {
+        let last_index = self.nodes.len() - 1;
+        let heuristic = |index: &usize| self.nodes[*index].min_remaining_distance as u64;
+        search::a_star(0, |index| self.successors(*index), |index| *index == last_index, heuristic)
+            .expect("path not found")
+            .cost
     }
 }
 
@@ -223,65 +161,25 @@ impl Node {
     }
 }
 
-#[derive(Clone)]
-struct PathNode {
-    distance: usize,
-    visited: bool,
-}
-
-impl Default for PathNode {
-    fn default() -> PathNode {
-        PathNode {
-            distance: usize::MAX,
-            visited: false,
-        }
-    }
-}
-
-#[derive(Eq)]
-struct OpenNode {
-    index: usize,
-    heuristic: usize,
-}
-
-impl PartialEq for OpenNode {
-    fn eq(&self, other: &Self) -> bool {
-        self.index == other.index
-    }
-}
-
-impl Ord for OpenNode {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.heuristic.cmp(&other.heuristic).reverse()
-    }
-}
-
-impl PartialOrd for OpenNode {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
+static TEST_INPUT: &str = indoc!{"
+    1163751742
+    1381373672
+    2136511328
+    3694931569
+    7463417111
+    1319128137
+    1359912421
+    3125421639
+    1293138521
+    2311944581
+"};
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            1163751742
-            1381373672
-            2136511328
-            3694931569
-            7463417111
-            1319128137
-            1359912421
-            3125421639
-            1293138521
-            2311944581
-        "};
-
         let day = Day15::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 40.into(), "Part1");
         assert_eq!(day.run_part2(), 315.into(), "Part2");
@@ -10,9 +10,13 @@ impl FromStr for Day16 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let data = hex::decode(s).unwrap();
+        let data = parse_line(s, |line| {
+            let data = line.parse_next_with(|token| hex::decode(token))?;
+            line.finish()?;
+            Ok(data)
+        })?;
         let mut binary_reader = BinaryReader::from_buffer(&data);
-        let packet = Packet::from_binary_reader(&mut binary_reader);
+        let packet = Packet::from_binary_reader(&mut binary_reader)?;
 
         Ok(Day16 { packet })
     }
@@ -20,6 +24,7 @@ impl FromStr for Day16 {
 
 impl Solver for Day16 {
     const INPUT_PATH: &'static str = "inputs/2021/16.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT_1A, TEST_INPUT_1B, TEST_INPUT_1C, TEST_INPUT_1D, TEST_INPUT_2A, TEST_INPUT_2B, TEST_INPUT_2C, TEST_INPUT_2D];
 
     fn run_part1(&self) -> SolverResult {
         self.packet.sum_verions().into()
@@ -38,49 +43,56 @@ struct Packet {
 }
 
 impl Packet {
-    fn from_binary_reader(binary_reader: &mut BinaryReader) -> Packet {
-        let version = binary_reader.read_bits(3);
-        let packet_type = binary_reader.read_bits(3);
+    fn from_binary_reader(binary_reader: &mut BinaryReader) -> Result<Packet, ParseSolverError> {
+        let version = binary_reader.read_bits(3)?;
+        let packet_type = binary_reader.read_bits(3)?;
         let packet_type = PacketType::from_usize(packet_type);
         let mut literal_value = 0;
         let mut sub_packets = Vec::new();
         if packet_type == PacketType::LiteralValue {
             loop {
-                let last = binary_reader.read_bits(1) == 0;
-                literal_value |= binary_reader.read_bits(4) as u64;
+                let last = binary_reader.read_bits(1)? == 0;
+                literal_value |= binary_reader.read_bits(4)? as u64;
                 if last {
                     break;
                 }
                 literal_value <<= 4;
             }
         } else {
-            let length_type_id = binary_reader.read_bits(1);
+            let length_type_id = binary_reader.read_bits(1)?;
             if length_type_id == 0 {
-                let sub_packets_length = binary_reader.read_bits(15);
+                let sub_packets_length = binary_reader.read_bits(15)?;
                 let end_position = binary_reader.bit_position() + sub_packets_length;
                 loop {
-                    let sub_packet = Packet::from_binary_reader(binary_reader);
+                    let sub_packet = Packet::from_binary_reader(binary_reader)?;
                     sub_packets.push(sub_packet);
-                    debug_assert!(binary_reader.bit_position() <= end_position);
+                    if binary_reader.bit_position() > end_position {
+                        return Err(parse_solver_error!("sub packets overflow their length (= {sub_packets_length})"));
+                    }
                     if binary_reader.bit_position() == end_position {
                         break;
                     }
                 }
             } else {
-                let sub_packets_count = binary_reader.read_bits(11);
+                let sub_packets_count = binary_reader.read_bits(11)?;
                 for _ in 0..sub_packets_count {
-                    let sub_packet = Packet::from_binary_reader(binary_reader);
+                    let sub_packet = Packet::from_binary_reader(binary_reader)?;
                     sub_packets.push(sub_packet);
                 }
             }
+
+            let is_comparison = matches!(packet_type, PacketType::GreaterThan | PacketType::LessThan | PacketType::EqualTo);
+            if is_comparison && sub_packets.len() != 2 {
+                return Err(parse_solver_error!("comparison packet with {} sub packets", sub_packets.len()));
+            }
         }
 
-        Packet {
+        Ok(Packet {
             version,
             packet_type,
             literal_value,
             sub_packets: sub_packets.into_boxed_slice()
-        }
+        })
     }
 
     fn sum_verions(&self) -> usize {
@@ -148,7 +160,7 @@ impl<'a> BinaryReader<'a> {
         self.position * 8 + self.bit_index
     }
 
-    fn read_bits(&mut self, bits_count: usize) -> usize {
+    fn read_bits(&mut self, bits_count: usize) -> Result<usize, ParseSolverError> {
         debug_assert!(bits_count > 0 && bits_count <= mem::size_of::<usize>() * 8);
 
         let mut reaming_bits_to_read = bits_count;
@@ -159,7 +171,8 @@ impl<'a> BinaryReader<'a> {
 
             let offset = 8 - (self.bit_index + bits_to_read_for_position);
             let mask = (1 << bits_to_read_for_position) - 1;
-            let bits = (self.buffer[self.position] as usize >> offset) & mask;
+            let byte = *self.buffer.get(self.position).ok_or(parse_solver_error!("unexpected end of packet"))?;
+            let bits = (byte as usize >> offset) & mask;
             value |= bits << reaming_bits_to_read;
 
             self.bit_index += bits_to_read_for_position;
@@ -169,45 +182,53 @@ impl<'a> BinaryReader<'a> {
             }
         }
 
-        value
+        Ok(value)
     }
 }
 
+static TEST_INPUT_1A: &str = "8A004A801A8002F478";
+
+static TEST_INPUT_1B: &str = "620080001611562C8802118E34";
+
+static TEST_INPUT_1C: &str = "C0015000016115A2E0802F182340";
+
+static TEST_INPUT_1D: &str = "A0016C880162017C3686B18A3D4780";
+
+static TEST_INPUT_2A: &str = "C200B40A82";
+
+static TEST_INPUT_2B: &str = "04005AC33890";
+
+static TEST_INPUT_2C: &str = "880086C3E88112";
+
+static TEST_INPUT_2D: &str = "CE00C43D881120";
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test() {
-        static TEST_INPUT_1A: &str = "8A004A801A8002F478";
         let day = Day16::from_str(TEST_INPUT_1A).unwrap();
         assert_eq!(day.run_part1(), 16.into(), "Part1A");
 
-        static TEST_INPUT_1B: &str = "620080001611562C8802118E34";
         let day = Day16::from_str(TEST_INPUT_1B).unwrap();
         assert_eq!(day.run_part1(), 12.into(), "Part1B");
 
-        static TEST_INPUT_1C: &str = "C0015000016115A2E0802F182340";
         let day = Day16::from_str(TEST_INPUT_1C).unwrap();
         assert_eq!(day.run_part1(), 23.into(), "Part1C");
 
-        static TEST_INPUT_1D: &str = "A0016C880162017C3686B18A3D4780";
         let day = Day16::from_str(TEST_INPUT_1D).unwrap();
         assert_eq!(day.run_part1(), 31.into(), "Part1D");
 
-        static TEST_INPUT_2A: &str = "C200B40A82";
         let day = Day16::from_str(TEST_INPUT_2A).unwrap();
         assert_eq!(day.run_part2(), 3.into(), "Part2A");
         
-        static TEST_INPUT_2B: &str = "04005AC33890";
         let day = Day16::from_str(TEST_INPUT_2B).unwrap();
         assert_eq!(day.run_part2(), 54.into(), "Part2B");
 
-        static TEST_INPUT_2C: &str = "880086C3E88112";
         let day = Day16::from_str(TEST_INPUT_2C).unwrap();
         assert_eq!(day.run_part2(), 7.into(), "Part2C");
 
-        static TEST_INPUT_2D: &str = "CE00C43D881120";
         let day = Day16::from_str(TEST_INPUT_2D).unwrap();
         assert_eq!(day.run_part2(), 9.into(), "Part2D");
     }
@@ -2,8 +2,6 @@ use std::ops::RangeInclusive;
 
 use crate::solvers::prelude::*;
 
-use regex::Regex;
-
 pub struct Day17 {
     target_area: Rect
 }
@@ -12,22 +10,30 @@ impl FromStr for Day17 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let regex = Regex::new(r"target area: x=(-?[0-9]*)..(-?[0-9]*), y=(-?[0-9]*)..(-?[0-9]*)").unwrap();
-        let captures = regex.captures(s).unwrap();
-
-        Ok(Day17 {
-            target_area: Rect {
-                x_min: captures.get(1).unwrap().as_str().parse().unwrap(),
-                x_max: captures.get(2).unwrap().as_str().parse().unwrap(),
-                y_min: captures.get(3).unwrap().as_str().parse().unwrap(),
-                y_max: captures.get(4).unwrap().as_str().parse().unwrap(),
-            }
-        })
+        let target_area = parse_line(s, |line| {
+            line.expect("target area: x=")?;
+            let x_min = line.parse_until("..")?;
+            let x_max = line.parse_until(",")?;
+            line.expect("y=")?;
+            let y_min = line.parse_until("..")?;
+            let y_max = line.parse_next()?;
+            line.finish()?;
+
+            Ok(Rect {
+                x_min,
+                x_max,
+                y_min,
+                y_max,
+            })
+        })?;
+
+        Ok(Day17 { target_area })
     }
 }
 
 impl Solver for Day17 {
     const INPUT_PATH: &'static str = "inputs/2021/17.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut y_velocity = -self.target_area.y_min - 1;
@@ -122,14 +128,14 @@ impl Rect {
     }
 }
 
+static TEST_INPUT: &str = "target area: x=20..30, y=-10..-5";
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = "target area: x=20..30, y=-10..-5";
-
         let day = Day17::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 45.into(), "Part1");
         assert_eq!(day.run_part2(), 112.into(), "Part2");
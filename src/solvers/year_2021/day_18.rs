@@ -1,4 +1,3 @@
-use std::str::Chars;
 use std::collections::VecDeque;
 use std::mem;
 
@@ -12,13 +11,11 @@ impl FromStr for Day18 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut pairs = Vec::new();
-        for line in s.lines() {
-            let mut chars = line.chars();
-            let first = chars.next().unwrap();
-            debug_assert_eq!(first, '[');
-            pairs.push(Box::new(Pair::from_chars(&mut chars)));
-        }
+        let pairs = parse_lines(s, |line| {
+            let pair = Pair::parse(line, 1)?;
+            line.finish()?;
+            Ok(Box::new(pair))
+        })?;
 
         Ok(Day18 { pairs })
     }
@@ -26,6 +23,7 @@ impl FromStr for Day18 {
 
 impl Solver for Day18 {
     const INPUT_PATH: &'static str = "inputs/2021/18.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut pairs = VecDeque::from(self.pairs.clone());
@@ -61,15 +59,19 @@ struct Pair {
 }
 
 impl Pair {
-    fn from_chars(chars: &mut Chars) -> Pair {
-        let left = Element::from_chars(chars);
-        let separator = chars.next().unwrap();
-        debug_assert_eq!(separator, ',');
-        let right = Element::from_chars(chars);
-        let end = chars.next().unwrap();
-        debug_assert_eq!(end, ']');
-
-        Pair { left, right }
+    // Reduced numbers never have pairs nested more than 4 levels deep
+    fn parse(line: &mut LineCursor, depth: usize) -> Result<Pair, ParseSolverError> {
+        if depth > 4 {
+            return Err(line.error(ParseErrorKind::InvalidValue, "pair nested more than 4 levels deep"));
+        }
+
+        line.expect("[")?;
+        let left = Element::parse(line, depth)?;
+        line.expect(",")?;
+        let right = Element::parse(line, depth)?;
+        line.expect("]")?;
+
+        Ok(Pair { left, right })
     }
 
     fn add(&mut self, other: Box<Pair>) {
@@ -151,11 +153,11 @@ enum Element {
 }
 
 impl Element {
-    fn from_chars(chars: &mut Chars) -> Element {
-        let first = chars.next().unwrap();
-        match first {
-            '[' => Element::Pair(Box::new(Pair::from_chars(chars))),
-            _ => Element::RegularNumber((first as i64) - ('0' as i64)),
+    fn parse(line: &mut LineCursor, depth: usize) -> Result<Element, ParseSolverError> {
+        if line.rest().trim_start().starts_with('[') {
+            Ok(Element::Pair(Box::new(Pair::parse(line, depth + 1)?)))
+        } else {
+            Ok(Element::RegularNumber(line.parse_char(|c| c.to_digit(10).map(i64::from))?))
         }
     }
 
@@ -246,26 +248,25 @@ struct ExplodeState {
     right_value: i64,
 }
 
+static TEST_INPUT: &str = indoc!{"
+    [[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+    [[[5,[2,8]],4],[5,[[9,9],0]]]
+    [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+    [[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+    [[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+    [[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+    [[[[5,4],[7,7]],8],[[8,3],8]]
+    [[9,3],[[9,9],[6,[4,9]]]]
+    [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+    [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            [[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-            [[[5,[2,8]],4],[5,[[9,9],0]]]
-            [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-            [[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-            [[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-            [[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-            [[[[5,4],[7,7]],8],[[8,3],8]]
-            [[9,3],[[9,9],[6,[4,9]]]]
-            [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-            [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
-        "};
-
         let day = Day18::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 4140.into(), "Part1");
         assert_eq!(day.run_part2(), 3993.into(), "Part2");
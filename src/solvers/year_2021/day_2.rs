@@ -8,17 +8,16 @@ impl FromStr for Day2 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut instructions: Vec<Instruction> = Vec::new();
-        for line in s.lines() {
-            let mut splits = line.split(' ');
-            let operation = splits.next().unwrap();
-            let value = splits.next().unwrap();
-
-            instructions.push(Instruction {
-                operation: str_to_operation(operation),
-                value: value.parse().unwrap(),
-            });
-        }
+        let instructions = parse_lines(s, |line| {
+            let operation = line.parse_next_with(str_to_operation)?;
+            let value = line.parse_next()?;
+            line.finish()?;
+
+            Ok(Instruction {
+                operation,
+                value,
+            })
+        })?;
 
         Ok(Day2 { instructions })
     }
@@ -26,6 +25,7 @@ impl FromStr for Day2 {
 
 impl Solver for Day2 {
     const INPUT_PATH: &'static str = "inputs/2021/02.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut horizontal = 0;
@@ -74,32 +74,31 @@ enum Operations {
     Down,
 }
 
-fn str_to_operation(s: &str) -> Operations
+fn str_to_operation(s: &str) -> Result<Operations, ParseSolverError>
 {
     match s {
-        "forward" => Operations::Forward,
-        "up" => Operations::Up,
-        "down" => Operations::Down,
-        _ => panic!("Invalid Operation {s}"),
+        "forward" => Ok(Operations::Forward),
+        "up" => Ok(Operations::Up),
+        "down" => Ok(Operations::Down),
+        _ => Err(parse_solver_error!("invalid operation")),
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    forward 5
+    down 5
+    forward 8
+    up 3
+    down 8
+    forward 2
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            forward 5
-            down 5
-            forward 8
-            up 3
-            down 8
-            forward 2
-        "};
-
         let day = Day2::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 150.into(), "Part1");
         assert_eq!(day.run_part2(), 900.into(), "Part2");
@@ -22,13 +22,14 @@ impl FromStr for Day21 {
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
         Ok(Day21 {
-            starting_positions: get_starting_positions(s)
+            starting_positions: get_starting_positions(s)?
         })
     }
 }
 
 impl Solver for Day21 {
     const INPUT_PATH: &'static str = "inputs/2021/21.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut game_state = GameState::new(&self.starting_positions, 1000);
@@ -56,24 +57,21 @@ impl Solver for Day21 {
     }
 }
 
-fn get_starting_positions(input: &str) -> [usize; PLAYER_COUNT] {
-    let mut starting_positions = [0; PLAYER_COUNT];
-
-    let mut index = 0;
-    for line in input.lines() {
-        starting_positions[index] = get_player_starting_position(line);
-        index += 1;
-    }
-
-    debug_assert!(index == PLAYER_COUNT, "The input player count (= {index}) doesn't match the expected player count (= {PLAYER_COUNT}).");
+fn get_starting_positions(input: &str) -> Result<[usize; PLAYER_COUNT], ParseSolverError> {
+    let starting_positions = parse_lines(input, |line| {
+        line.expect("Player")?;
+        line.parse_until::<usize>("starting position:")?;
+        let position: usize = line.parse_next()?;
+        line.finish()?;
+        if !(1..=BOARD_SIZE).contains(&position) {
+            return Err(ParseSolverError::with_kind(ParseErrorKind::InvalidValue, format!("position {position} is not between 1 and {BOARD_SIZE}")));
+        }
+        Ok(position - 1)
+    })?;
 
     starting_positions
-}
-
-fn get_player_starting_position(line: &str) -> usize {
-    debug_assert!(line.len() > 28);
-    let value: usize = line[28..line.len()].parse().unwrap();
-    value - 1
+        .try_into()
+        .map_err(|positions: Vec<usize>| parse_solver_error!("expected {PLAYER_COUNT} players, found {}", positions.len()))
 }
 
 fn dirac_turn(game_state: GameState, player_index: usize, universe_count: usize, player_win_counts: &mut [usize; 2]) {
@@ -147,18 +145,17 @@ impl DiracRoll {
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    Player 1 starting position: 4
+    Player 2 starting position: 8
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            Player 1 starting position: 4
-            Player 2 starting position: 8
-        "};
-
         let day = Day21::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 739785.into(), "Part1");
         assert_eq!(day.run_part2(), 444356092776315_i64.into(), "Part2");
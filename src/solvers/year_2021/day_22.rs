@@ -1,8 +1,5 @@
-use std::ops::Add;
-
 use crate::solvers::prelude::*;
-
-use regex::Regex;
+use crate::utils::{Bound3D, BoundSet};
 
 pub struct Day22 {
     reboot_steps: Box<[RebootStep]>
@@ -12,22 +9,24 @@ impl FromStr for Day22 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let regex = Regex::new(r"(\w*) x=(-?\d*)..(-?\d*),y=(-?\d*)..(-?\d*),z=(-?\d*)..(-?\d*)").unwrap();
-
-        let mut reboot_steps = Vec::new();
-        for line in s.lines() {
-            let captures = regex.captures(line).unwrap();
-            let value = str_to_bool(captures.get(1).unwrap().as_str());
-            let cuboid = Cuboid {
-                min: [captures.get(2).unwrap().as_str().parse().unwrap(), captures.get(4).unwrap().as_str().parse().unwrap(), captures.get(6).unwrap().as_str().parse().unwrap()],
-                max: [captures.get(3).unwrap().as_str().parse().unwrap(), captures.get(5).unwrap().as_str().parse().unwrap(), captures.get(7).unwrap().as_str().parse().unwrap()],
-            };
-
-            reboot_steps.push(RebootStep {
+        let reboot_steps = parse_lines(s, |line| {
+            let value = line.parse_next_with(str_to_bool)?;
+            line.expect("x=")?;
+            let x_min = line.parse_until("..")?;
+            let x_max = line.parse_until(",")?;
+            line.expect("y=")?;
+            let y_min = line.parse_until("..")?;
+            let y_max = line.parse_until(",")?;
+            line.expect("z=")?;
+            let z_min = line.parse_until("..")?;
+            let z_max = line.parse_next()?;
+            line.finish()?;
+
+            Ok(RebootStep {
                 value,
-                cuboid,
-            });
-        }
+                cuboid: Bound3D::from_min_max((x_min, y_min, z_min), (x_max, y_max, z_max)),
+            })
+        })?;
 
         Ok(Day22 {
             reboot_steps: reboot_steps.into_boxed_slice()
@@ -37,294 +36,155 @@ impl FromStr for Day22 {
 
 impl Solver for Day22 {
     const INPUT_PATH: &'static str = "inputs/2021/22.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT_1A, TEST_INPUT_1B, TEST_INPUT_2];
 
     fn run_part1(&self) -> SolverResult {
-        let starting_cubiod = Cuboid::new(-50, 50);
-        let mut root = Node::new(starting_cubiod, false);
-        root.apply_procedures(&self.reboot_steps);
-
-        let mut count = 0;
-        root.count(true, &mut count);
-
-        count.into()
+        let region = Bound3D::from_min_max([-50; 3], [50; 3]);
+        self.reboot(Some(region)).into()
     }
 
     fn run_part2(&self) -> SolverResult {
-        let starting_cubiod = Cuboid::new(i64::MIN, i64::MAX);
-        let mut root = Node::new(starting_cubiod, false);
-        root.apply_procedures(&self.reboot_steps);
-
-        let mut count = 0;
-        root.count(true, &mut count);
-
-        count.into()
+        self.reboot(None).into()
     }
 }
 
-fn str_to_bool(s: &str) -> bool {
-    match s {
-        "on" => true,
-        "off" => false,
-        _ => panic!("invalid string: {s}"),
-    }
-}
-
-#[derive(Clone)]
-struct Cuboid {
-    min: [i64; 3],
-    max: [i64; 3],
-}
-
-impl Cuboid {
-    fn new (min: i64, max: i64) -> Cuboid {
-        Cuboid {
-            min: [min; 3],
-            max: [max; 3],
-        }
-    }
-
-    fn size(&self) -> i64 {
-        (self.max[0] - self.min[0] + 1) *
-        (self.max[1] - self.min[1] + 1) *
-        (self.max[2] - self.min[2] + 1)
-    }
-
-    fn overlap(&self, other: &Cuboid) -> OverlapResult {
-        self.overlap_axis(other, 0) +
-        self.overlap_axis(other, 1) +
-        self.overlap_axis(other, 2)
-    }
+impl Day22 {
+    // Number of cubes on after the reboot, only inside the region if any
+    fn reboot(&self, region: Option<Bound3D>) -> i64 {
+        let mut cubes_on = BoundSet::new();
+        for reboot_step in self.reboot_steps.iter() {
+            let cuboid = match region {
+                Some(region) => match reboot_step.cuboid.and(&region) {
+                    Some(cuboid) => cuboid,
+                    None => continue,
+                },
+                None => reboot_step.cuboid,
+            };
 
-    fn overlap_axis(&self, other: &Cuboid, axis_index: usize) -> OverlapResult {
-        if self.min[axis_index] <= other.max[axis_index] && self.max[axis_index] >= other.min[axis_index] {
-            if self.min[axis_index] <= other.min[axis_index] && self.max[axis_index] >= other.max[axis_index] {
-                return OverlapResult::Encompass;
+            if reboot_step.value {
+                cubes_on.insert(cuboid);
             } else {
-                return OverlapResult::Intersect;
+                cubes_on.remove(cuboid);
             }
         }
-
-        OverlapResult::None
+        cubes_on.volume()
     }
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
-enum OverlapResult {
-    None = 0,
-    Intersect = 1,
-    Encompass = 2,
-}
-
-impl Add for OverlapResult {
-    type Output = Self;
-
-    fn add(self, other: Self) -> Self {
-        OverlapResult::min(self, other)
+fn str_to_bool(s: &str) -> Result<bool, ParseSolverError> {
+    match s {
+        "on" => Ok(true),
+        "off" => Ok(false),
+        _ => Err(parse_solver_error!("expected `on` or `off`")),
     }
 }
 
 struct RebootStep {
     value: bool,
-    cuboid: Cuboid,
-}
-
-struct Node {
-    cuboid: Cuboid,
-    node_type: NodeType,
+    cuboid: Bound3D,
 }
 
-impl Node {
-    fn new(cuboid: Cuboid, value: bool) -> Node {
-        Node {
-            cuboid,
-            node_type: NodeType::Leaf(value),
-        }
-    }
-
-    fn apply_procedures(&mut self, reboot_steps: &[RebootStep]) {
-        for reboot_step in reboot_steps {
-            self.apply_procedure(reboot_step);
-        }
-    }
-
-    fn apply_procedure(&mut self, reboot_step: &RebootStep) {
-        match reboot_step.cuboid.overlap(&self.cuboid) {
-            OverlapResult::Intersect => self.split(reboot_step),
-            OverlapResult::Encompass => self.node_type = NodeType::Leaf(reboot_step.value),
-            OverlapResult::None => {},
-        }
-    }
-
-    fn split(&mut self, reboot_step: &RebootStep) {
-        match &mut self.node_type {
-            NodeType::Branch(nodes) => {
-                for node in nodes.iter_mut() {
-                    node.apply_procedure(reboot_step);
-                }
-            }
-            NodeType::Leaf(previous_value) => {
-                if *previous_value == reboot_step.value {
-                    // No need to split it's the same value.
-                    return;
-                }
-
-                let mut new_nodes = vec![Node::new(self.cuboid.clone(), reboot_step.value)];
-
-                for i in 0..3 {
-                    let node = new_nodes.last().unwrap();
-                    if reboot_step.cuboid.min[i] > node.cuboid.min[i] && reboot_step.cuboid.min[i] <= node.cuboid.max[i] {
-                        let mut cuboid_a = node.cuboid.clone();
-                        let mut cuboid_b = node.cuboid.clone();
-                        cuboid_a.max[i] = reboot_step.cuboid.min[i] - 1;
-                        cuboid_b.min[i] = reboot_step.cuboid.min[i];
-                        new_nodes.remove(new_nodes.len() - 1);
-                        new_nodes.push(Node::new(cuboid_a, *previous_value));
-                        new_nodes.push(Node::new(cuboid_b, reboot_step.value));
-                    }
-                }
-
-                for i in 0..3 {
-                    let node = new_nodes.last().unwrap();
-                    if reboot_step.cuboid.max[i] >= node.cuboid.min[i] && reboot_step.cuboid.max[i] < node.cuboid.max[i] {
-                        let mut cuboid_a = node.cuboid.clone();
-                        let mut cuboid_b = node.cuboid.clone();
-                        cuboid_a.min[i] = reboot_step.cuboid.max[i] + 1;
-                        cuboid_b.max[i] = reboot_step.cuboid.max[i];
-                        new_nodes.remove(new_nodes.len() - 1);
-                        new_nodes.push(Node::new(cuboid_a, *previous_value));
-                        new_nodes.push(Node::new(cuboid_b, reboot_step.value));
-                    }
-                }
-
-                debug_assert!(new_nodes.len() >= 2);
-                self.node_type = NodeType::Branch(new_nodes);
-            }
-        }
-    }
-
-    fn count(&self, value: bool, count: &mut i64) {
-        match &self.node_type {
-            NodeType::Branch(nodes) => {
-                for node in nodes {
-                    node.count(value, count);
-                }
-            }
-            NodeType::Leaf(leaf_value) => {
-                if *leaf_value == value {
-                    *count += self.cuboid.size();
-                }
-            }
-        }
-    }
-}
-
-enum NodeType {
-    Branch(Vec<Node>),
-    Leaf(bool),
-}
+static TEST_INPUT_1A: &str = indoc!{"
+    on x=10..12,y=10..12,z=10..12
+    on x=11..13,y=11..13,z=11..13
+    off x=9..11,y=9..11,z=9..11
+    on x=10..10,y=10..10,z=10..10
+"};
+
+static TEST_INPUT_1B: &str = indoc!{"
+    on x=-20..26,y=-36..17,z=-47..7
+    on x=-20..33,y=-21..23,z=-26..28
+    on x=-22..28,y=-29..23,z=-38..16
+    on x=-46..7,y=-6..46,z=-50..-1
+    on x=-49..1,y=-3..46,z=-24..28
+    on x=2..47,y=-22..22,z=-23..27
+    on x=-27..23,y=-28..26,z=-21..29
+    on x=-39..5,y=-6..47,z=-3..44
+    on x=-30..21,y=-8..43,z=-13..34
+    on x=-22..26,y=-27..20,z=-29..19
+    off x=-48..-32,y=26..41,z=-47..-37
+    on x=-12..35,y=6..50,z=-50..-2
+    off x=-48..-32,y=-32..-16,z=-15..-5
+    on x=-18..26,y=-33..15,z=-7..46
+    off x=-40..-22,y=-38..-28,z=23..41
+    on x=-16..35,y=-41..10,z=-47..6
+    off x=-32..-23,y=11..30,z=-14..3
+    on x=-49..-5,y=-3..45,z=-29..18
+    off x=18..30,y=-20..-8,z=-3..13
+    on x=-41..9,y=-7..43,z=-33..15
+    on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+    on x=967..23432,y=45373..81175,z=27513..53682
+"};
+
+static TEST_INPUT_2: &str = indoc!{"
+    on x=-5..47,y=-31..22,z=-19..33
+    on x=-44..5,y=-27..21,z=-14..35
+    on x=-49..-1,y=-11..42,z=-10..38
+    on x=-20..34,y=-40..6,z=-44..1
+    off x=26..39,y=40..50,z=-2..11
+    on x=-41..5,y=-41..6,z=-36..8
+    off x=-43..-33,y=-45..-28,z=7..25
+    on x=-33..15,y=-32..19,z=-34..11
+    off x=35..47,y=-46..-34,z=-11..5
+    on x=-14..36,y=-6..44,z=-16..29
+    on x=-57795..-6158,y=29564..72030,z=20435..90618
+    on x=36731..105352,y=-21140..28532,z=16094..90401
+    on x=30999..107136,y=-53464..15513,z=8553..71215
+    on x=13528..83982,y=-99403..-27377,z=-24141..23996
+    on x=-72682..-12347,y=18159..111354,z=7391..80950
+    on x=-1060..80757,y=-65301..-20884,z=-103788..-16709
+    on x=-83015..-9461,y=-72160..-8347,z=-81239..-26856
+    on x=-52752..22273,y=-49450..9096,z=54442..119054
+    on x=-29982..40483,y=-108474..-28371,z=-24328..38471
+    on x=-4958..62750,y=40422..118853,z=-7672..65583
+    on x=55694..108686,y=-43367..46958,z=-26781..48729
+    on x=-98497..-18186,y=-63569..3412,z=1232..88485
+    on x=-726..56291,y=-62629..13224,z=18033..85226
+    on x=-110886..-34664,y=-81338..-8658,z=8914..63723
+    on x=-55829..24974,y=-16897..54165,z=-121762..-28058
+    on x=-65152..-11147,y=22489..91432,z=-58782..1780
+    on x=-120100..-32970,y=-46592..27473,z=-11695..61039
+    on x=-18631..37533,y=-124565..-50804,z=-35667..28308
+    on x=-57817..18248,y=49321..117703,z=5745..55881
+    on x=14781..98692,y=-1341..70827,z=15753..70151
+    on x=-34419..55919,y=-19626..40991,z=39015..114138
+    on x=-60785..11593,y=-56135..2999,z=-95368..-26915
+    on x=-32178..58085,y=17647..101866,z=-91405..-8878
+    on x=-53655..12091,y=50097..105568,z=-75335..-4862
+    on x=-111166..-40997,y=-71714..2688,z=5609..50954
+    on x=-16602..70118,y=-98693..-44401,z=5197..76897
+    on x=16383..101554,y=4615..83635,z=-44907..18747
+    off x=-95822..-15171,y=-19987..48940,z=10804..104439
+    on x=-89813..-14614,y=16069..88491,z=-3297..45228
+    on x=41075..99376,y=-20427..49978,z=-52012..13762
+    on x=-21330..50085,y=-17944..62733,z=-112280..-30197
+    on x=-16478..35915,y=36008..118594,z=-7885..47086
+    off x=-98156..-27851,y=-49952..43171,z=-99005..-8456
+    off x=2032..69770,y=-71013..4824,z=7471..94418
+    on x=43670..120875,y=-42068..12382,z=-24787..38892
+    off x=37514..111226,y=-45862..25743,z=-16714..54663
+    off x=25699..97951,y=-30668..59918,z=-15349..69697
+    off x=-44271..17935,y=-9516..60759,z=49131..112598
+    on x=-61695..-5813,y=40978..94975,z=8655..80240
+    off x=-101086..-9439,y=-7088..67543,z=33935..83858
+    off x=18020..114017,y=-48931..32606,z=21474..89843
+    off x=-77139..10506,y=-89994..-18797,z=-80..59318
+    off x=8476..79288,y=-75520..11602,z=-96624..-24783
+    on x=-47488..-1262,y=24338..100707,z=16292..72967
+    off x=-84341..13987,y=2429..92914,z=-90671..-1318
+    off x=-37810..49457,y=-71013..-7894,z=-105357..-13188
+    off x=-27365..46395,y=31009..98017,z=15428..76570
+    off x=-70369..-16548,y=22648..78696,z=-1892..86821
+    on x=-53470..21291,y=-120233..-33476,z=-44150..38147
+    off x=-93533..-4276,y=-16170..68771,z=-104985..-24507
+"};
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT_1A: &str = indoc!{"
-            on x=10..12,y=10..12,z=10..12
-            on x=11..13,y=11..13,z=11..13
-            off x=9..11,y=9..11,z=9..11
-            on x=10..10,y=10..10,z=10..10
-        "};
-
-        static TEST_INPUT_1B: &str = indoc!{"
-            on x=-20..26,y=-36..17,z=-47..7
-            on x=-20..33,y=-21..23,z=-26..28
-            on x=-22..28,y=-29..23,z=-38..16
-            on x=-46..7,y=-6..46,z=-50..-1
-            on x=-49..1,y=-3..46,z=-24..28
-            on x=2..47,y=-22..22,z=-23..27
-            on x=-27..23,y=-28..26,z=-21..29
-            on x=-39..5,y=-6..47,z=-3..44
-            on x=-30..21,y=-8..43,z=-13..34
-            on x=-22..26,y=-27..20,z=-29..19
-            off x=-48..-32,y=26..41,z=-47..-37
-            on x=-12..35,y=6..50,z=-50..-2
-            off x=-48..-32,y=-32..-16,z=-15..-5
-            on x=-18..26,y=-33..15,z=-7..46
-            off x=-40..-22,y=-38..-28,z=23..41
-            on x=-16..35,y=-41..10,z=-47..6
-            off x=-32..-23,y=11..30,z=-14..3
-            on x=-49..-5,y=-3..45,z=-29..18
-            off x=18..30,y=-20..-8,z=-3..13
-            on x=-41..9,y=-7..43,z=-33..15
-            on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
-            on x=967..23432,y=45373..81175,z=27513..53682
-        "};
-
-        static TEST_INPUT_2: &str = indoc!{"
-            on x=-5..47,y=-31..22,z=-19..33
-            on x=-44..5,y=-27..21,z=-14..35
-            on x=-49..-1,y=-11..42,z=-10..38
-            on x=-20..34,y=-40..6,z=-44..1
-            off x=26..39,y=40..50,z=-2..11
-            on x=-41..5,y=-41..6,z=-36..8
-            off x=-43..-33,y=-45..-28,z=7..25
-            on x=-33..15,y=-32..19,z=-34..11
-            off x=35..47,y=-46..-34,z=-11..5
-            on x=-14..36,y=-6..44,z=-16..29
-            on x=-57795..-6158,y=29564..72030,z=20435..90618
-            on x=36731..105352,y=-21140..28532,z=16094..90401
-            on x=30999..107136,y=-53464..15513,z=8553..71215
-            on x=13528..83982,y=-99403..-27377,z=-24141..23996
-            on x=-72682..-12347,y=18159..111354,z=7391..80950
-            on x=-1060..80757,y=-65301..-20884,z=-103788..-16709
-            on x=-83015..-9461,y=-72160..-8347,z=-81239..-26856
-            on x=-52752..22273,y=-49450..9096,z=54442..119054
-            on x=-29982..40483,y=-108474..-28371,z=-24328..38471
-            on x=-4958..62750,y=40422..118853,z=-7672..65583
-            on x=55694..108686,y=-43367..46958,z=-26781..48729
-            on x=-98497..-18186,y=-63569..3412,z=1232..88485
-            on x=-726..56291,y=-62629..13224,z=18033..85226
-            on x=-110886..-34664,y=-81338..-8658,z=8914..63723
-            on x=-55829..24974,y=-16897..54165,z=-121762..-28058
-            on x=-65152..-11147,y=22489..91432,z=-58782..1780
-            on x=-120100..-32970,y=-46592..27473,z=-11695..61039
-            on x=-18631..37533,y=-124565..-50804,z=-35667..28308
-            on x=-57817..18248,y=49321..117703,z=5745..55881
-            on x=14781..98692,y=-1341..70827,z=15753..70151
-            on x=-34419..55919,y=-19626..40991,z=39015..114138
-            on x=-60785..11593,y=-56135..2999,z=-95368..-26915
-            on x=-32178..58085,y=17647..101866,z=-91405..-8878
-            on x=-53655..12091,y=50097..105568,z=-75335..-4862
-            on x=-111166..-40997,y=-71714..2688,z=5609..50954
-            on x=-16602..70118,y=-98693..-44401,z=5197..76897
-            on x=16383..101554,y=4615..83635,z=-44907..18747
-            off x=-95822..-15171,y=-19987..48940,z=10804..104439
-            on x=-89813..-14614,y=16069..88491,z=-3297..45228
-            on x=41075..99376,y=-20427..49978,z=-52012..13762
-            on x=-21330..50085,y=-17944..62733,z=-112280..-30197
-            on x=-16478..35915,y=36008..118594,z=-7885..47086
-            off x=-98156..-27851,y=-49952..43171,z=-99005..-8456
-            off x=2032..69770,y=-71013..4824,z=7471..94418
-            on x=43670..120875,y=-42068..12382,z=-24787..38892
-            off x=37514..111226,y=-45862..25743,z=-16714..54663
-            off x=25699..97951,y=-30668..59918,z=-15349..69697
-            off x=-44271..17935,y=-9516..60759,z=49131..112598
-            on x=-61695..-5813,y=40978..94975,z=8655..80240
-            off x=-101086..-9439,y=-7088..67543,z=33935..83858
-            off x=18020..114017,y=-48931..32606,z=21474..89843
-            off x=-77139..10506,y=-89994..-18797,z=-80..59318
-            off x=8476..79288,y=-75520..11602,z=-96624..-24783
-            on x=-47488..-1262,y=24338..100707,z=16292..72967
-            off x=-84341..13987,y=2429..92914,z=-90671..-1318
-            off x=-37810..49457,y=-71013..-7894,z=-105357..-13188
-            off x=-27365..46395,y=31009..98017,z=15428..76570
-            off x=-70369..-16548,y=22648..78696,z=-1892..86821
-            on x=-53470..21291,y=-120233..-33476,z=-44150..38147
-            off x=-93533..-4276,y=-16170..68771,z=-104985..-24507
-        "};
-
         let day = Day22::from_str(TEST_INPUT_1A).unwrap();
         assert_eq!(day.run_part1(), 39.into(), "Part1A");
 
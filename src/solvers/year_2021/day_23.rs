@@ -1,15 +1,11 @@
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet};
-
 use crate::solvers::prelude::*;
-
-use regex::Regex;
+use crate::utils::search;
 
 const ROOM_COUNT: usize = 4;
 
-static REGEX: &str = r"#([A-D])#([A-D])#([A-D])#([A-D])#";
-static NEW_RAW_1: &str = "#D#C#B#A#";
-static NEW_RAW_2: &str = "#D#B#A#C#";
+// #D#C#B#A# and #D#B#A#C#
+static NEW_ROW_1: [u8; ROOM_COUNT] = [3, 2, 1, 0];
+static NEW_ROW_2: [u8; ROOM_COUNT] = [3, 1, 0, 2];
 static MOVE_COSTS: [u32; ROOM_COUNT] = [1, 10, 100, 1000];
 static VALID_HALLWAY_INDEXES: [usize; 7] = [0, 1, 3, 5, 7, 9, 10];
 static ENTRANCE_HALLWAY_INDEXES: [usize; ROOM_COUNT] = [2, 4, 6, 8];
@@ -22,11 +18,18 @@ impl FromStr for Day23 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let regex = Regex::new(REGEX).unwrap();
-        let mut lines = s.lines();
+        // Amphipods are on the third and fourth lines
+        let rows: Vec<[u8; ROOM_COUNT]> = parse_lines(s, |line| match line.number() {
+            3 | 4 => parse_row(line).map(Some),
+            _ => Ok(None),
+        })?.into_iter().flatten().collect();
+        let [first_row, second_row] = rows[..] else {
+            return Err(parse_solver_error!("expected 2 rows of amphipods, found {}", rows.len()));
+        };
+
         let mut initial_state = State::new();
-        initial_state.add_line_to_rooms(lines.nth(2).unwrap(), &regex, 0);
-        initial_state.add_line_to_rooms(lines.next().unwrap(), &regex, 1);
+        initial_state.set_row(&first_row, 0);
+        initial_state.set_row(&second_row, 1);
 
         Ok(Day23 { initial_state })
     }
@@ -34,44 +37,40 @@ impl FromStr for Day23 {
 
 impl Solver for Day23 {
     const INPUT_PATH: &'static str = "inputs/2021/23.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         search(&self.initial_state).into()
     }
 
     fn run_part2(&self) -> SolverResult {
-        let regex = Regex::new(REGEX).unwrap();
         let mut initial_state: State<4> = self.initial_state.resize();
         initial_state.swap_room_depths(1, 3);
-        initial_state.add_line_to_rooms(NEW_RAW_1, &regex, 1);
-        initial_state.add_line_to_rooms(NEW_RAW_2, &regex, 2);
+        initial_state.set_row(&NEW_ROW_1, 1);
+        initial_state.set_row(&NEW_ROW_2, 2);
         
         search(&initial_state).into()
     }
 }
 
-fn search<const ROOM_SIZE: usize>(initial_state: &State<ROOM_SIZE>) -> i64 {
-    let mut close_set = HashSet::new();
-    let mut open_set = BinaryHeap::new();
-    open_set.push(Node::new(initial_state, 0));
-    
-    while let Some(node) = open_set.pop() {
-        if node.state.is_finished() {
-            return i64::from(node.cost);
-        }
-
-        for next_node in node.available_moves() {
-            if close_set.contains(&next_node.state) {
-                continue;
-            }
-
-            open_set.push(next_node);
-        }
-
-        close_set.insert(node.state);
+// Amphipods of a row like `###B#C#B#D###`, 0 for A to 3 for D
+fn parse_row(line: &mut LineCursor) -> Result<[u8; ROOM_COUNT], ParseSolverError> {
+    let mut row = [0; ROOM_COUNT];
+    line.take_while(|c| c == '#');
+    for amphipod in row.iter_mut() {
+        *amphipod = line.parse_char(|c| ('A'..='D').contains(&c).then(|| c as u8 - b'A'))?;
+        line.expect("#")?;
     }
+    line.take_while(|c| c == '#');
+    line.finish()?;
+    Ok(row)
+}
 
-    panic!("No solution found");
+// The minimum cost to finish never overestimates the remaining cost
+fn search<const ROOM_SIZE: usize>(initial_state: &State<ROOM_SIZE>) -> u64 {
+    search::a_star(initial_state.clone(), MoveIterator::new, State::is_finished, |state| u64::from(state.min_cost_to_finish()))
+        .expect("No solution found")
+        .cost
 }
 
 #[derive(Clone)]
@@ -103,12 +102,10 @@ impl<const ROOM_SIZE: usize> State<ROOM_SIZE> {
         new_state
     }
 
-    fn add_line_to_rooms(&mut self, line: &str, regex: &Regex, room_depth: usize) {
+    fn set_row(&mut self, row: &[u8; ROOM_COUNT], room_depth: usize) {
         debug_assert!(room_depth < ROOM_SIZE);
-        let captures = regex.captures(line).unwrap();
-        for room_index in 0..ROOM_COUNT {
-            let capture = captures.get(room_index + 1).unwrap().as_str().chars().next().unwrap();
-            self.rooms[room_index][room_depth] = ((capture as usize) - ('A' as usize)) as u8;
+        for (room, amphipod) in self.rooms.iter_mut().zip(row) {
+            room[room_depth] = *amphipod;
         }
     }
 
@@ -223,66 +220,28 @@ impl<const ROOM_SIZE: usize> State<ROOM_SIZE> {
     }
 }
 
-#[derive(Eq)]
-struct Node<const ROOM_SIZE: usize> {
+// Next states with the cost of the move
+struct MoveIterator<const ROOM_SIZE: usize> {
     state: State<ROOM_SIZE>,
-    cost: u32,
-    heuristic: u32,
-}
-
-impl<const ROOM_SIZE: usize> Node<ROOM_SIZE> {
-    fn new(state: &State<ROOM_SIZE>, cost: u32) -> Self {
-        Node {
-            state: state.clone(),
-            cost,
-            heuristic: cost + state.min_cost_to_finish(),
-        }
-    }
-
-    fn available_moves(&self) -> MoveIterator<ROOM_SIZE> {
-        MoveIterator::new(self)
-    }
-}
-
-impl<const ROOM_SIZE: usize> PartialEq for Node<ROOM_SIZE> {
-    fn eq(&self, other: &Self) -> bool {
-        self.state == other.state
-    }
-}
-
-impl<const ROOM_SIZE: usize> Ord for Node<ROOM_SIZE> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.heuristic.cmp(&other.heuristic).reverse()
-    }
-}
-
-impl<const ROOM_SIZE: usize> PartialOrd for Node<ROOM_SIZE> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-struct MoveIterator<'a, const ROOM_SIZE: usize> {
-    node: &'a Node<ROOM_SIZE>,
     room_index: usize,
     hallway_index: usize,
 }
 
-impl<'a, const ROOM_SIZE: usize> MoveIterator<'a, ROOM_SIZE> {
-    fn new(node: &'a Node<ROOM_SIZE>) -> Self {
+impl<const ROOM_SIZE: usize> MoveIterator<ROOM_SIZE> {
+    fn new(state: &State<ROOM_SIZE>) -> Self {
         MoveIterator {
-            node,
+            state: state.clone(),
             room_index: 0,
             hallway_index: 0,
         }
     }
 }
 
-impl<'a, const ROOM_SIZE: usize> Iterator for MoveIterator<'a, ROOM_SIZE> {
-    type Item = Node<ROOM_SIZE>;
+impl<const ROOM_SIZE: usize> Iterator for MoveIterator<ROOM_SIZE> {
+    type Item = (State<ROOM_SIZE>, u64);
     
     fn next(&mut self) -> Option<Self::Item> {
-        let current_state = &self.node.state;
+        let current_state = &self.state;
         let mut next = None;
         while next.is_none() && self.room_index < ROOM_COUNT {
             if current_state.has_stranger(self.room_index) {
@@ -308,8 +267,8 @@ impl<'a, const ROOM_SIZE: usize> Iterator for MoveIterator<'a, ROOM_SIZE> {
                         let mut new_state = current_state.clone();
                         new_state.rooms[self.room_index][room_depth] = u8::MAX;
                         new_state.hallway[to_hallway_index] = amphipod_to_move;
-                        let new_cost = self.node.cost + distance * MOVE_COSTS[amphipod_to_move as usize];
-                        next = Some(Node::new(&new_state, new_cost));
+                        let move_cost = distance * MOVE_COSTS[amphipod_to_move as usize];
+                        next = Some((new_state, u64::from(move_cost)));
                     }
                 }
 
@@ -336,8 +295,8 @@ impl<'a, const ROOM_SIZE: usize> Iterator for MoveIterator<'a, ROOM_SIZE> {
                         let mut new_state = current_state.clone();
                         new_state.hallway[from_hallway_index] = u8::MAX;
                         new_state.rooms[to_room_index][room_depth] = amphipod_at;
-                        let new_cost = self.node.cost + distance * MOVE_COSTS[to_room_index];
-                        next = Some(Node::new(&new_state, new_cost));
+                        let move_cost = distance * MOVE_COSTS[to_room_index];
+                        next = Some((new_state, u64::from(move_cost)));
                     }
                 }
             }
@@ -349,21 +308,20 @@ impl<'a, const ROOM_SIZE: usize> Iterator for MoveIterator<'a, ROOM_SIZE> {
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    #############
+    #...........#
+    ###B#C#B#D###
+      #A#D#C#A#
+      #########
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            #############
-            #...........#
-            ###B#C#B#D###
-              #A#D#C#A#
-              #########
-        "};
-
         let day = Day23::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 12521.into(), "Part1");
         assert_eq!(day.run_part2(), 44169.into(), "Part2");
@@ -1,7 +1,8 @@
 use crate::solvers::prelude::*;
+use crate::utils::{Array2D, Point2D};
 
 pub struct Day25 {
-    map: Map<SeaCucumber>
+    map: Array2D<SeaCucumber>
 }
 
 impl FromStr for Day25 {
@@ -9,17 +10,18 @@ impl FromStr for Day25 {
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
         Ok(Day25 {
-            map: Map::from_input(s)
+            map: Array2D::from_str_map(s, false, |_, c| char_to_sea_cucumber(c))?
         })
     }
 }
 
 impl Solver for Day25 {
     const INPUT_PATH: &'static str = "inputs/2021/25.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut map = self.map.clone();
-        let mut can_move = Map::new(map.width, map.height);
+        let mut can_move = Array2D::new(map.sizes());
         let mut i = 1;
         while step(&mut map, &mut can_move)  {
             i += 1;
@@ -33,111 +35,57 @@ impl Solver for Day25 {
     }
 }
 
-fn step(map: &mut Map<SeaCucumber>, map_can_move: &mut Map<bool>) -> bool {
-    let mut any_east_move = false;
-    for y in 0..map.height {
-    for x in 0..map.width {
-        let index = map.get_index(x, y);
-        if map.array[index] == SeaCucumber::East {
-            let forward = map.get_index(x + 1, y);
-            let can_move = map.array[forward] == SeaCucumber::None;
-            map_can_move.array[index] = can_move;
-            any_east_move |= can_move;
-        }
-    }}
-
-    if any_east_move {
-        for y in 0..map.height {
-        for x in 0..map.width {
-            let index = map.get_index(x, y);
-            if map.array[index] == SeaCucumber::East && map_can_move.array[index] {
-                let forward = map.get_index(x + 1, y);
-                map.array[index] = SeaCucumber::None;
-                map.array[forward] = SeaCucumber::East;
-                map_can_move.array[forward] = false;
-            }
-        }}
-    }
+fn step(map: &mut Array2D<SeaCucumber>, map_can_move: &mut Array2D<bool>) -> bool {
+    let any_east_move = move_herd(map, map_can_move, SeaCucumber::East);
+    let any_south_move = move_herd(map, map_can_move, SeaCucumber::South);
+    any_east_move | any_south_move
+}
 
-    let mut any_south_move = false;
-    for y in 0..map.height {
-    for x in 0..map.width {
-        let index = map.get_index(x, y);
-        if map.array[index] == SeaCucumber::South {
-            let forward = map.get_index(x, y + 1);
-            let can_move = map.array[forward] == SeaCucumber::None;
-            map_can_move.array[index] = can_move;
-            any_south_move |= can_move;
+fn move_herd(map: &mut Array2D<SeaCucumber>, map_can_move: &mut Array2D<bool>, herd: SeaCucumber) -> bool {
+    let mut any_move = false;
+    for y in 0..map.height() {
+    for x in 0..map.width() {
+        let coords = Point2D::new(x, y);
+        if map[coords] == herd {
+            let (_, forward) = forward_neighbour(map, coords, herd);
+            let can_move = *forward == SeaCucumber::None;
+            map_can_move[coords] = can_move;
+            any_move |= can_move;
         }
     }}
 
-    if any_south_move {
-        for y in 0..map.height {
-        for x in 0..map.width {
-            let index = map.get_index(x, y);
-            if map.array[index] == SeaCucumber::South && map_can_move.array[index] {
-                let forward = map.get_index(x, y + 1);
-                map.array[index] = SeaCucumber::None;
-                map.array[forward] = SeaCucumber::South;
-                map_can_move.array[forward] = false;
+    if any_move {
+        for y in 0..map.height() {
+        for x in 0..map.width() {
+            let coords = Point2D::new(x, y);
+            if map[coords] == herd && map_can_move[coords] {
+                let (forward, _) = forward_neighbour(map, coords, herd);
+                map[coords] = SeaCucumber::None;
+                map[forward] = herd;
+                map_can_move[forward] = false;
             }
         }}
     }
 
-    any_east_move | any_south_move
-}
-
-#[derive(Clone)]
-struct Map<T> {
-    array: Box<[T]>,
-    width: usize,
-    height: usize,
-}
-
-impl<T> Map<T> {
-    fn get_index(&self, mut x: usize, mut y: usize) -> usize {
-        x %= self.width;
-        y %= self.height;
-        x + y * self.width
-    }
-}
-
-impl<T: Clone + Default> Map<T> {
-    fn new(width: usize, height: usize) -> Map<T> {
-        Map {
-            array: vec![T::default(); width * height].into_boxed_slice(),
-            width,
-            height,
-        }
-    }
+    any_move
 }
 
-impl Map<SeaCucumber> {
-    fn from_input(input: &str) -> Map<SeaCucumber> {
-        let width = input.lines().next().unwrap().chars().count();
-        let heigth = input.lines().count();
-        let mut map = Map::new(width, heigth);
-
-        for (y, line) in input.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            let index = map.get_index(x, y);
-            map.array[index] = char_to_sea_cucumber(c);
-        }}
-
-        map
-    }
+// The map is a torus, the von Neumann neighbours are ordered -x, +x, -y, +y and y grows downwards
+fn forward_neighbour(map: &Array2D<SeaCucumber>, coords: Point2D, herd: SeaCucumber) -> (Point2D, &SeaCucumber) {
+    let index = if herd == SeaCucumber::East { 1 } else { 3 };
+    map.von_neumann_neighbours_wrap(coords).nth(index).unwrap()
 }
 
-fn char_to_sea_cucumber(c: char) -> SeaCucumber {
+fn char_to_sea_cucumber(c: char) -> Result<SeaCucumber, ParseSolverError> {
     match c {
-        '.' => SeaCucumber::None,
-        '>' => SeaCucumber::East,
-        'v' => SeaCucumber::South,
-        _=> panic!("invalid character: {c}"),
+        '.' => Ok(SeaCucumber::None),
+        '>' => Ok(SeaCucumber::East),
+        'v' => Ok(SeaCucumber::South),
+        _ => Err(ParseSolverError::with_kind(ParseErrorKind::InvalidValue, format!("invalid character: {c}"))),
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 #[derive(Debug)]
 #[derive(Default)]
 #[derive(Eq, PartialEq)]
@@ -148,25 +96,24 @@ enum SeaCucumber {
     South,
 }
 
+static TEST_INPUT: &str = indoc!{"
+    v...>>.vv>
+    .vv>>.vv..
+    >>.>v>...v
+    >>v>>.>.v.
+    v>v.vv.v..
+    >.>>..v...
+    .vv..>.>v.
+    v.v..>>v.v
+    ....v..v.>
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            v...>>.vv>
-            .vv>>.vv..
-            >>.>v>...v
-            >>v>>.>.v.
-            v>v.vv.v..
-            >.>>..v...
-            .vv..>.>v.
-            v.v..>>v.v
-            ....v..v.>
-        "};
-
         let day = Day25::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 58.into(), "Part1");
         assert_eq!(day.run_part2(), SolverResult::Invalid, "Part2");
@@ -9,14 +9,19 @@ impl FromStr for Day3 {
     type Err = ParseSolverError;
 
     fn from_str(input: &str) -> Result<Self, ParseSolverError> {
-        let bit_count = input.lines().next().unwrap().len();
-        let values = input.lines().map(|line| i64::from_str_radix(line, 2).unwrap()).collect();
+        let bit_count = input.lines().next().ok_or(parse_solver_error!("empty input"))?.len();
+        let values = parse_lines(input, |line| {
+            let value = line.parse_next_with(|token| i64::from_str_radix(token, 2))?;
+            line.finish()?;
+            Ok(value)
+        })?;
         Ok(Day3 { bit_count, values })
     }
 }
 
 impl Solver for Day3 {
     const INPUT_PATH: &'static str = "inputs/2021/03.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut counts = vec![0; self.bit_count];
@@ -118,28 +123,27 @@ impl Day3 {
     }
 }
 
+static TEST_INPUT: &str = indoc!{"
+    00100
+    11110
+    10110
+    10111
+    10101
+    01111
+    00111
+    11100
+    10000
+    11001
+    00010
+    01010
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            00100
-            11110
-            10110
-            10111
-            10101
-            01111
-            00111
-            11100
-            10000
-            11001
-            00010
-            01010
-        "};
-
         let day = Day3::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 198.into(), "Part1");
         assert_eq!(day.run_part2(), 230.into(), "Part2");
@@ -12,21 +12,22 @@ impl FromStr for Day4 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let pat = if s.contains('\r') { "\r\n\r\n" } else { "\n\n" };
-        let mut splits = s.split(pat);
+        let sections = sections(s);
+        let (numbers_section, board_sections) = sections.split_first().ok_or(parse_solver_error!("empty input"))?;
+        let numbers = numbers_section.parse_line(|line| line.parse_separated(","))?;
 
-        let numbers = splits.next().unwrap().split(',').map(|value| value.parse().unwrap()).collect();
-        
         let mut boards = Vec::new();
-        for split in splits {
-            let mut board: [i64; BOARD_CAPACITY] = [0; BOARD_CAPACITY];
-            for (y, line) in split.lines().enumerate() {
-                let numbers = line.split_whitespace().map(|s| s.parse::<i64>().unwrap());
-                for (x, number) in numbers.enumerate() {
-                    let cell_index = get_cell_index(x, y);
-                    board[cell_index] = number;
+        for section in board_sections {
+            let rows = section.parse_lines(|line| {
+                let row: Vec<i64> = line.parse_list()?;
+                if row.len() != BOARD_SIZE {
+                    return Err(parse_solver_error!("expected {BOARD_SIZE} numbers, found {}", row.len()));
                 }
-            }
+                Ok(row)
+            })?;
+            let board = rows.concat()
+                .try_into()
+                .map_err(|_| parse_solver_error!("expected {BOARD_SIZE} rows, found {}", rows.len()).at(section.first_line(), None, section.text()))?;
 
             boards.push(board);
         }
@@ -37,6 +38,7 @@ impl FromStr for Day4 {
 
 impl Solver for Day4 {
     const INPUT_PATH: &'static str = "inputs/2021/04.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         self.run_bingo(false).into()
@@ -126,35 +128,34 @@ fn get_score(board: &[i64], board_progression: u32, last_number: i64) -> i64{
     sum * last_number
 }
 
+static TEST_INPUT: &str = indoc!{"
+    7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+    22 13 17 11  0
+     8  2 23  4 24
+    21  9 14 16  7
+     6 10  3 18  5
+     1 12 20 15 19
+    
+     3 15  0  2 22
+     9 18 13 17  5
+    19  8  7 25 23
+    20 11 10 24  4
+    14 21 16 12  6
+    
+    14 21 17 24  4
+    10 16 15  9 19
+    18  8 23 26 20
+    22 11 13  6  5
+     2  0 12  3  7
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-            22 13 17 11  0
-             8  2 23  4 24
-            21  9 14 16  7
-             6 10  3 18  5
-             1 12 20 15 19
-            
-             3 15  0  2 22
-             9 18 13 17  5
-            19  8  7 25 23
-            20 11 10 24  4
-            14 21 16 12  6
-            
-            14 21 17 24  4
-            10 16 15  9 19
-            18  8 23 26 20
-            22 11 13  6  5
-             2  0 12  3  7
-        "};
-
         let day = Day4::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 4512.into(), "Part1");
         assert_eq!(day.run_part2(), 1924.into(), "Part2");
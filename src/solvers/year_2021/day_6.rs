@@ -8,13 +8,14 @@ impl FromStr for Day6 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let fishes = s.split(',').map(|fish| fish.parse().unwrap()).collect();
+        let fishes = parse_line(s, |line| line.parse_separated(","))?;
         Ok(Day6 { fishes })
     }
 }
 
 impl Solver for Day6 {
     const INPUT_PATH: &'static str = "inputs/2021/06.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         self.simulate(80).into()
@@ -46,14 +47,14 @@ impl Day6 {
     }
 }
 
+static TEST_INPUT: &str = "3,4,3,1,2";
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = "3,4,3,1,2";
-
         let day = Day6::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 5934.into(), "Part1");
         assert_eq!(day.run_part2(), 26984457539_i64.into(), "Part2");
@@ -8,13 +8,14 @@ impl FromStr for Day7 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let values = s.split(',').map(|value| value.parse().unwrap()).collect();
+        let values = parse_line(s, |line| line.parse_separated(","))?;
         Ok(Day7 { values })
     }
 }
 
 impl Solver for Day7 {
     const INPUT_PATH: &'static str = "inputs/2021/07.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut previous = self.compute_fuel_needed_part1(0);
@@ -69,14 +70,14 @@ impl Day7 {
     }
 }
 
+static TEST_INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
-
         let day = Day7::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 37.into(), "Part1");
         assert_eq!(day.run_part2(), 168.into(), "Part2");
@@ -8,22 +8,15 @@ impl FromStr for Day8 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut entries = Vec::new();
-        for line in s.lines() {
-            let mut splits = line.split(" | ");
+        let entries = parse_lines(s, |line| {
+            let digits: Vec<Digit> = line.parse_list_until("|")?;
+            let digits = digits.try_into().map_err(|digits: Vec<Digit>| parse_solver_error!("expected 10 digits, found {}", digits.len()))?;
 
-            let mut digits: [Digit; 10] = Default::default();
-            for (index, digit) in splits.next().unwrap().split_whitespace().enumerate() {
-                digits[index] = Digit::from_str(digit);
-            }
-
-            let mut outputs: [Digit; 4] = Default::default();
-            for (index, output) in splits.next().unwrap().split_whitespace().enumerate() {
-                outputs[index] = Digit::from_str(output);
-            }
+            let outputs: Vec<Digit> = line.parse_list()?;
+            let outputs = outputs.try_into().map_err(|outputs: Vec<Digit>| parse_solver_error!("expected 4 outputs, found {}", outputs.len()))?;
 
-            entries.push(Entry { digits, outputs });
-        }
+            Ok(Entry { digits, outputs })
+        })?;
 
         Ok(Day8 { entries })
     }
@@ -31,6 +24,7 @@ impl FromStr for Day8 {
 
 impl Solver for Day8 {
     const INPUT_PATH: &'static str = "inputs/2021/08.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut count = 0;
@@ -103,27 +97,31 @@ struct Entry {
     outputs: [Digit; 4],
 }
 
-#[derive(Default)]
 struct Digit {
     bits: usize,
     length: usize,
 }
 
-impl Digit {
-    fn from_str(s: &str) -> Digit {
+impl FromStr for Digit {
+    type Err = ParseSolverError;
+
+    fn from_str(s: &str) -> Result<Self, ParseSolverError> {
         let mut bits = 0;
         for c in s.chars() {
-            let offset = (c as usize) - ('a' as usize);
-            debug_assert!(offset < 7);
-            bits |= 1 << offset;
+            if !('a'..='g').contains(&c) {
+                return Err(parse_solver_error!("invalid segment `{c}`"));
+            }
+            bits |= 1 << (c as usize - 'a' as usize);
         }
 
-        Digit {
+        Ok(Digit {
             bits,
             length: s.len(),
-        }
+        })
     }
+}
 
+impl Digit {
     fn contains(&self, bits: usize) -> bool {
         self.bits & bits == bits
     }
@@ -139,26 +137,25 @@ fn index_of(ordered_digits: &[usize], bits: usize) -> i64 {
     panic!("digit not found");
 }
 
+static TEST_INPUT: &str = indoc!{"
+    be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+    edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+    fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+    fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+    aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+    fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+    dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+    bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+    egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+    gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+"};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
     #[test]
     fn test() {
-        static TEST_INPUT: &str = indoc!{"
-            be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
-            edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
-            fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
-            fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
-            aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
-            fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
-            dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
-            bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
-            egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-            gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
-        "};
-
         let day = Day8::from_str(TEST_INPUT).unwrap();
         assert_eq!(day.run_part1(), 26.into(), "Part1");
         assert_eq!(day.run_part2(), 61229.into(), "Part2");
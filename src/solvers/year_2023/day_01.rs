@@ -18,6 +18,7 @@ impl FromStr for Day01 {
 
 impl Solver for Day01 {
     const INPUT_PATH: &'static str = "inputs/2023/01.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT_1, TEST_INPUT_2];
 
     fn run_part1(&self) -> SolverResult {
         sum_lines(self.input.as_str(), false)
@@ -80,27 +81,26 @@ fn try_parse_number(s: &str) -> Option<usize> {
     None
 }
 
+static TEST_INPUT_1: &str = indoc!{
+   "1abc2
+    pqr3stu8vwx
+    a1b2c3d4e5f
+    treb7uchet"
+};
+
+static TEST_INPUT_2: &str = indoc!{
+   "two1nine
+    eightwothree
+    abcone2threexyz
+    xtwone3four
+    4nineeightseven2
+    zoneight234
+    7pqrstsixteen"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT_1: &str = indoc!{
-       "1abc2
-        pqr3stu8vwx
-        a1b2c3d4e5f
-        treb7uchet"
-    };
-
-    static TEST_INPUT_2: &str = indoc!{
-       "two1nine
-        eightwothree
-        abcone2threexyz
-        xtwone3four
-        4nineeightseven2
-        zoneight234
-        7pqrstsixteen"
-    };
 
     #[test]
     fn test() {
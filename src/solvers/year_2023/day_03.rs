@@ -41,6 +41,7 @@ impl FromStr for Day03 {
 
 impl Solver for Day03 {
     const INPUT_PATH: &'static str = "inputs/2023/03.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut sum = 0;
@@ -113,23 +114,22 @@ fn is_symbol(c: char) -> bool {
     (c < '0' || c > '9') && c != '.'
 }
 
+static TEST_INPUT: &str = indoc!{
+   "467..114..
+    ...*......
+    ..35..633.
+    ......#...
+    617*......
+    .....+.58.
+    ..592.....
+    ......755.
+    ...$.*....
+    .664.598.."
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "467..114..
-        ...*......
-        ..35..633.
-        ......#...
-        617*......
-        .....+.58.
-        ..592.....
-        ......755.
-        ...$.*....
-        .664.598.."
-    };
 
     #[test]
     fn test() {
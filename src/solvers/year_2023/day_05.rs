@@ -1,25 +1,31 @@
 use crate::solvers::prelude::*;
+use crate::utils::{IntervalSet, Range, RangeMap};
 
 use itertools::Itertools;
 
 pub struct Day05 {
     seeds: Vec<i64>,
-    maps: Vec<Map>,
+    maps: Vec<RangeMap>,
 }
 
 impl FromStr for Day05 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let delimiter = if s.contains('\r') { "\r\n\r\n" } else { "\n\n" };
-        let (seeds, maps) = s.split_once(delimiter).ok_or(ParseSolverError::new(format!("fail to split input with delimiter: {delimiter}")))?;
-
-        let seeds = seeds[7..].split_whitespace()
-            .map(|seed| seed.parse())
-            .try_collect()?;
+        let sections = sections(s);
+        let (seeds_section, map_sections) = sections.split_first().ok_or(parse_solver_error!("empty input"))?;
+
+        // Seeds are also read as pairs of start and length
+        let seeds: Vec<i64> = seeds_section.parse_line(|line| {
+            line.expect("seeds:")?;
+            line.parse_list()
+        })?;
+        if seeds.is_empty() || !seeds.len().is_multiple_of(2) {
+            return Err(parse_solver_error!("expected pairs of seeds, found {} seeds", seeds.len()));
+        }
 
-        let maps = maps.split(delimiter)
-            .map(|map| map.parse())
+        let maps = map_sections.iter()
+            .map(parse_map)
             .try_collect()?;
 
         Ok(Day05 { seeds, maps })
@@ -28,13 +34,14 @@ impl FromStr for Day05 {
 
 impl Solver for Day05 {
     const INPUT_PATH: &'static str = "inputs/2023/05.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut min = i64::MAX;
         for number in self.seeds.iter() {
             let mut number = *number;
             for map in self.maps.iter() {
-                number = map.convert(number);
+                number = map.map(number);
             }
             if number < min {
                 min = number;
@@ -46,194 +53,76 @@ impl Solver for Day05 {
 
     fn run_part2(&self) -> SolverResult {
         debug_assert!(self.seeds.len() % 2 == 0);
-        let mut min = i64::MAX;
-        for seed_range in self.seeds.chunks(2) {
-            let mut seed_ranges = vec![Range {
-                start: seed_range[0],
-                length: seed_range[1],
-            }];
+        let mut seeds = IntervalSet::from_ranges(self.seeds
+            .chunks(2)
+            .map(|seed_range| Range::new(seed_range[0], seed_range[1])));
 
-            for map in self.maps.iter() {
-                map.convert_ranges(&mut seed_ranges);
-            }
-
-            let range_min = seed_ranges.iter()
-                .map(|range| range.start)
-                .min()
-                .unwrap();
-
-            if range_min < min {
-                min = range_min;
-            }
+        for map in self.maps.iter() {
+            seeds = map.map_set(&seeds);
         }
 
-        min.into()
+        seeds.min().unwrap().into()
     }
 }
 
-struct Map {
-    ranges: Vec<MapRange>,
-}
-
-impl FromStr for Map {
-    type Err = ParseSolverError;
-
-    fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut lines = s.lines();
-        lines.next();
-        let ranges = lines.map(|range| range.parse::<MapRange>()).try_collect()?;
-        Ok(Map { ranges })
-    }
-}
-
-impl Map {
-    fn convert(&self, number: i64) -> i64 {
-        for range in self.ranges.iter() {
-            if range.contains(number) {
-                return number + range.diff();
-            }
-        }
-        number
-    }
-
-    fn convert_ranges(&self, ranges: &mut Vec<Range>) {
-        let mut converted_ranges = Vec::new(); // this vector could be reuse to avoid allocation
-        let mut i = 0;
-        'outter: while  i < ranges.len() {
-            let mut range = ranges[i];
-            for map_range in self.ranges.iter() {
-                if range.start <= map_range.source_range.end() && range.end() >= map_range.source_range.start {
-                    if range.start >= map_range.source_range.start && range.end() <= map_range.source_range.end() {
-                        converted_ranges.push(Range {
-                            start: range.start + map_range.diff(),
-                            length: range.length,
-                        });
-                        ranges.swap_remove(i);
-                        continue 'outter;
-                    }
-                    else if range.start >= map_range.source_range.start {
-                        converted_ranges.push(Range {
-                            start: range.start + map_range.diff(),
-                            length: map_range.source_range.end() - range.start + 1,
-                        });
-                        range = Range {
-                            start: map_range.source_range.end() + 1,
-                            length: range.end() - map_range.source_range.end(),
-                        };
-                        ranges[i] = range;
-                    }
-                    else if range.end() <= map_range.source_range.end() {
-                        converted_ranges.push(Range {
-                            start: map_range.destination_start,
-                            length: range.end() - map_range.source_range.start + 1,
-                        });
-                        range.length = map_range.source_range.start - range.start;
-                        ranges[i] = range;
-                    }
-                    else {
-                        converted_ranges.push(Range {
-                            start: map_range.destination_start,
-                            length: map_range.source_range.length,
-                        });
-                        ranges.push(Range {
-                            start: map_range.source_range.end() + 1,
-                            length: range.end() - map_range.source_range.end(),
-                        });
-                        range.length = map_range.source_range.start - range.start;
-                        ranges[i] = range;
-                    }
-                }
-            }
-
-            i += 1;
+// Header line, then `destination_start source_start length` lines
+fn parse_map(section: &Section) -> Result<RangeMap, ParseSolverError> {
+    let mut map = RangeMap::new();
+    section.parse_lines(|line| {
+        if line.number() == 1 {
+            line.take_until("map:")?;
+            return line.finish();
         }
 
-        ranges.append(&mut converted_ranges);
-    }
-}
-
-struct MapRange {
-    source_range: Range,
-    destination_start: i64,
+        let destination_start = line.parse_next()?;
+        let source_start: i64 = line.parse_next()?;
+        let length: i64 = line.parse_next()?;
+        line.finish()?;
+        map.insert_destination(Range::new(source_start, length), destination_start);
+        Ok(())
+    })?;
+    Ok(map)
 }
 
-impl FromStr for MapRange {
-    type Err = ParseSolverError;
-
-    fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut split = s.split_whitespace();
-        Ok(MapRange {
-            destination_start: split.next().unwrap().parse()?,
-            source_range: Range {
-                start: split.next().unwrap().parse()?,
-                length: split.next().unwrap().parse()?,
-            },
-        })
-    }
-}
-
-impl MapRange {
-    fn contains(&self, number: i64) -> bool {
-        number >= self.source_range.start && number <= self.source_range.end()
-    }
-
-    fn diff(&self) -> i64 {
-        self.destination_start - self.source_range.start
-    }
-}
-
-#[derive(Copy, Clone)]
-struct Range {
-    start: i64,
-    length: i64,
-}
-
-impl Range {
-    fn end(&self) -> i64 {
-        self.start + self.length - 1
-    }
-}
+static TEST_INPUT: &str = indoc!{
+   "seeds: 79 14 55 13
+
+    seed-to-soil map:
+    50 98 2
+    52 50 48
+    
+    soil-to-fertilizer map:
+    0 15 37
+    37 52 2
+    39 0 15
+    
+    fertilizer-to-water map:
+    49 53 8
+    0 11 42
+    42 0 7
+    57 7 4
+    
+    water-to-light map:
+    88 18 7
+    18 25 70
+    
+    light-to-temperature map:
+    45 77 23
+    81 45 19
+    68 64 13
+    
+    temperature-to-humidity map:
+    0 69 1
+    1 0 69
+    
+    humidity-to-location map:
+    60 56 37
+    56 93 4"
+};
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "seeds: 79 14 55 13
-
-        seed-to-soil map:
-        50 98 2
-        52 50 48
-        
-        soil-to-fertilizer map:
-        0 15 37
-        37 52 2
-        39 0 15
-        
-        fertilizer-to-water map:
-        49 53 8
-        0 11 42
-        42 0 7
-        57 7 4
-        
-        water-to-light map:
-        88 18 7
-        18 25 70
-        
-        light-to-temperature map:
-        45 77 23
-        81 45 19
-        68 64 13
-        
-        temperature-to-humidity map:
-        0 69 1
-        1 0 69
-        
-        humidity-to-location map:
-        60 56 37
-        56 93 4"
-    };
 
     #[test]
     fn test() {
@@ -1,23 +1,26 @@
 use crate::solvers::prelude::*;
 
 pub struct Day06 {
-    times: String,
-    distances: String,
+    times: Vec<i64>,
+    distances: Vec<i64>,
 }
 
 impl FromStr for Day06 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut lines = s.lines();
-
-        let times = lines.next()
-            .ok_or(ParseSolverError::new("times not found"))?[5..]
-            .to_string();
-
-        let distances = lines.next()
-            .ok_or(ParseSolverError::new("distances not found"))?[9..]
-            .to_string();
+        let lines = parse_lines(s, |line| {
+            line.expect(if line.number() == 1 { "Time:" } else { "Distance:" })?;
+            let values = line.parse_list::<u32>()?;
+            Ok(values.into_iter().map(i64::from).collect())
+        })?;
+
+        let [times, distances]: [Vec<i64>; 2] = lines
+            .try_into()
+            .map_err(|lines: Vec<Vec<i64>>| parse_solver_error!("expected 2 lines, found {}", lines.len()))?;
+        if times.len() != distances.len() {
+            return Err(parse_solver_error!("found {} times but {} distances", times.len(), distances.len()));
+        }
 
         Ok(Day06 { times, distances })
     }
@@ -25,32 +28,30 @@ impl FromStr for Day06 {
 
 impl Solver for Day06 {
     const INPUT_PATH: &'static str = "inputs/2023/06.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
-        let times = self.times.split_whitespace()
-            .map(|time| time.parse().unwrap());
-
-        let distances = self.distances.split_whitespace()
-            .map(|time| time.parse().unwrap());
-
-        times.zip(distances)
-            .fold(1, |acc, (time, distance)| acc * compute_beat_record(time, distance))
+        self.times.iter()
+            .zip(self.distances.iter())
+            .fold(1, |acc, (time, distance)| acc * compute_beat_record(*time, *distance))
             .into()
     }
 
     fn run_part2(&self) -> SolverResult {
-        let time = self.times.chars()
-            .filter_map(|c| c.to_digit(10))
-            .fold(0i64, |acc, d| (acc * 10) + i64::from(d));
-
-        let distance = self.distances.chars()
-            .filter_map(|c| c.to_digit(10))
-            .fold(0i64, |acc, d| (acc * 10) + i64::from(d));
+        let time = concat_digits(&self.times);
+        let distance = concat_digits(&self.distances);
 
         compute_beat_record_binary_search(time, distance).into()
     }
 }
 
+// Values written next to each other, ignoring the spaces between them
+fn concat_digits(values: &[i64]) -> i64 {
+    values.iter()
+        .flat_map(|value| value.to_string().into_bytes())
+        .fold(0, |acc, digit| (acc * 10) + i64::from(digit - b'0'))
+}
+
 fn compute_beat_record(time: i64, distance: i64) -> i64 {
     (1..time)
         .filter(|i| compute_distance(*i, time) > distance)
@@ -87,15 +88,14 @@ fn compute_distance(hold: i64, time: i64) -> i64 {
     hold * (time - hold)
 }
 
+static TEST_INPUT: &str = indoc!{
+   "Time:      7  15   30
+    Distance:  9  40  200"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "Time:      7  15   30
-        Distance:  9  40  200"
-    };
 
     #[test]
     fn test() {
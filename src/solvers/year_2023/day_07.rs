@@ -4,8 +4,6 @@ use std::convert::Into;
 
 use crate::solvers::prelude::*;
 
-use itertools::Itertools;
-
 pub struct Day07 {
     hands: Vec<Hand>,
 }
@@ -14,15 +12,14 @@ impl FromStr for Day07 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let hands = s.lines()
-            .map(|hand| hand.parse())
-            .try_collect()?;
+        let hands = parse_lines(s, Hand::parse)?;
         Ok(Day07 { hands })
     }
 }
 
 impl Solver for Day07 {
     const INPUT_PATH: &'static str = "inputs/2023/07.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         compute_total_winnings(self.hands.as_slice(), false).into()
@@ -146,31 +143,22 @@ impl Hand {
     }
 }
 
-impl FromStr for Hand {
-    type Err = ParseSolverError;
-
-    fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let cards = parse_cards(&s[0..5])?;
-        let bid = s[6..].parse()?;
+impl Hand {
+    fn parse(line: &mut LineCursor) -> Result<Hand, ParseSolverError> {
+        let cards = line.parse_next_with(parse_cards)?;
+        let bid = line.parse_next()?;
+        line.finish()?;
         Ok(Hand { cards, bid })
     }
 }
 
 fn parse_cards(s: &str) -> Result<[char; 5], ParseSolverError> {
-    let mut cards: [char; 5] = ['x'; 5];
-    
-    for (i, c) in s.chars().enumerate() {
-        if i >= 5 {
-            return Err(ParseSolverError::new(format!("fail to parse hand: {}", s)));
-        }
-        cards[i] = c;
+    if let Some(card) = s.chars().find(|card| !"23456789TJQKA".contains(*card)) {
+        return Err(parse_solver_error!("invalid card: {card}"));
     }
 
-    if cards[4] == 'x' {
-        Err(ParseSolverError::new(format!("fail to parse hand: {}", s)))
-    } else {
-        Ok(cards)
-    }
+    let cards: Vec<char> = s.chars().collect();
+    cards.try_into().map_err(|cards: Vec<char>| parse_solver_error!("expected 5 cards, found {}", cards.len()))
 }
 
 #[derive(Eq)]
@@ -195,18 +183,17 @@ impl PartialEq for StrengthBid {
     }
 }
 
+static TEST_INPUT: &str = indoc!{
+   "32T3K 765
+    T55J5 684
+    KK677 28
+    KTJJT 220
+    QQQJA 483"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "32T3K 765
-        T55J5 684
-        KK677 28
-        KTJJT 220
-        QQQJA 483"
-    };
 
     #[test]
     fn test() {
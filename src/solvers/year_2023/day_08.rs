@@ -13,21 +13,36 @@ impl FromStr for Day08 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut lines = s.lines();
-
-        let instructions = lines.next()
-            .ok_or(ParseSolverError::new("instruction not found"))?
-            .chars()
-            .collect();
-
-        lines.next();
-
-        let mut map = HashMap::new();
-        for line in lines {
-            let node = parse_node_id(&line[0..3]);
-            let left = parse_node_id(&line[7..10]);
-            let right = parse_node_id(&line[12..15]);
-            map.insert(node, (left, right));
+        let [instructions_section, nodes_section] = &sections(s)[..] else {
+            return Err(parse_solver_error!("expected instructions and nodes separated by an empty line"));
+        };
+
+        // There is at least one instruction
+        let instructions: Vec<char> = instructions_section.parse_line(|line| {
+            let mut instructions = vec![line.parse_char(parse_instruction)?];
+            while !line.is_empty() {
+                instructions.push(line.parse_char(parse_instruction)?);
+            }
+            Ok(instructions)
+        })?;
+
+        let nodes = nodes_section.parse_lines(|line| {
+            let node = parse_node_id(line)?;
+            line.expect("=")?;
+            line.expect("(")?;
+            let left = parse_node_id(line)?;
+            line.expect(",")?;
+            let right = parse_node_id(line)?;
+            line.expect(")")?;
+            line.finish()?;
+            Ok((node, (left, right)))
+        })?;
+
+        let map: HashMap<NodeId, (NodeId, NodeId)> = nodes.into_iter().collect();
+        for (left, right) in map.values() {
+            if let Some(node) = [left, right].into_iter().find(|node| !map.contains_key(*node)) {
+                return Err(parse_solver_error!("unknown node: {}", node.iter().collect::<String>()));
+            }
         }
 
         Ok(Day08 { instructions, map, })
@@ -36,6 +51,7 @@ impl FromStr for Day08 {
 
 impl Solver for Day08 {
     const INPUT_PATH: &'static str = "inputs/2023/08.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT_1A, TEST_INPUT_1B, TEST_INPUT_2];
 
     fn run_part1(&self) -> SolverResult {
         self.simulate(['A', 'A', 'A'], |current| current == ['Z', 'Z', 'Z']).into()
@@ -69,52 +85,52 @@ impl Day08 {
 
 type NodeId = [char; 3];
 
-fn parse_node_id(s: &str) -> NodeId {
-    debug_assert_eq!(s.len(), 3);
-    let mut node_id = ['x'; 3];
-    for (i, c) in s.chars().enumerate() {
-        node_id[i] = c;
-    }
-    node_id
+fn parse_instruction(c: char) -> Option<char> {
+    matches!(c, 'L' | 'R').then_some(c)
+}
+
+fn parse_node_id(line: &mut LineCursor) -> Result<NodeId, ParseSolverError> {
+    let id = line.take_while(|c| c.is_ascii_alphanumeric());
+    let chars: Vec<char> = id.chars().collect();
+    chars.try_into().map_err(|_| line.error_at(line.position() - id.len(), ParseErrorKind::InvalidValue, format!("expected a 3 characters node, found `{id}`")))
 }
 
+static TEST_INPUT_1A: &str = indoc!{
+   "RL
+
+    AAA = (BBB, CCC)
+    BBB = (DDD, EEE)
+    CCC = (ZZZ, GGG)
+    DDD = (DDD, DDD)
+    EEE = (EEE, EEE)
+    GGG = (GGG, GGG)
+    ZZZ = (ZZZ, ZZZ)"
+};
+
+static TEST_INPUT_1B: &str = indoc!{
+   "LLR
+
+    AAA = (BBB, BBB)
+    BBB = (AAA, ZZZ)
+    ZZZ = (ZZZ, ZZZ)"
+};
+
+static TEST_INPUT_2: &str = indoc!{
+   "LR
+
+    11A = (11B, XXX)
+    11B = (XXX, 11Z)
+    11Z = (11B, XXX)
+    22A = (22B, XXX)
+    22B = (22C, 22C)
+    22C = (22Z, 22Z)
+    22Z = (22B, 22B)
+    XXX = (XXX, XXX)"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT_1A: &str = indoc!{
-       "RL
-
-        AAA = (BBB, CCC)
-        BBB = (DDD, EEE)
-        CCC = (ZZZ, GGG)
-        DDD = (DDD, DDD)
-        EEE = (EEE, EEE)
-        GGG = (GGG, GGG)
-        ZZZ = (ZZZ, ZZZ)"
-    };
-
-    static TEST_INPUT_1B: &str = indoc!{
-       "LLR
-
-        AAA = (BBB, BBB)
-        BBB = (AAA, ZZZ)
-        ZZZ = (ZZZ, ZZZ)"
-    };
-
-    static TEST_INPUT_2: &str = indoc!{
-       "LR
-
-        11A = (11B, XXX)
-        11B = (XXX, 11Z)
-        11Z = (11B, XXX)
-        22A = (22B, XXX)
-        22B = (22C, 22C)
-        22C = (22Z, 22Z)
-        22Z = (22B, 22B)
-        XXX = (XXX, XXX)"
-     };
 
     #[test]
     fn test() {
@@ -1,5 +1,5 @@
 use crate::solvers::prelude::*;
-use crate::utils::{Array2D, Point2D};
+use crate::utils::{Array2D, Direction2D, Point2D};
 
 pub struct Day10 {
     grid: Array2D<Tile>,
@@ -10,7 +10,7 @@ impl FromStr for Day10 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        // parse input
+        // parse input, North is up
         let mut start = None;
         let mut grid = Array2D::from_str_map(s, true, |coords, c| {
             if c == 'S' {
@@ -34,6 +34,7 @@ impl FromStr for Day10 {
 
 impl Solver for Day10 {
     const INPUT_PATH: &'static str = "inputs/2023/10.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT_1A, TEST_INPUT_1B, TEST_INPUT_2A, TEST_INPUT_2B, TEST_INPUT_2C, TEST_INPUT_2D];
 
     fn run_part1(&self) -> SolverResult {
         let distance = run_loop(&self.grid, self.start, None);
@@ -57,7 +58,7 @@ impl Solver for Day10 {
 
                 if !was_on_loop && tile != Tile::Empty {
                     let enter_direction = tile.definition().connections.unwrap()[0];
-                    debug_assert!(enter_direction == Point2D::UP || enter_direction == Point2D::DOWN);
+                    debug_assert!(enter_direction.is_vertical());
                     state = OnLoop {
                         was_inside: state == Inside,
                         enter_direction: enter_direction,
@@ -70,7 +71,7 @@ impl Solver for Day10 {
                 }
 
                 if let OnLoop{was_inside, enter_direction} = &state {
-                    if tile.definition().contains_connection(enter_direction.opposite()) {
+                    if tile.definition().contains_connection(enter_direction.reverse()) {
                         state = if *was_inside { Outside } else { Inside };
                     } else if !entered_loop_this_frame && tile.definition().contains_connection(*enter_direction) {
                         state = if *was_inside { Inside } else { Outside };
@@ -85,12 +86,12 @@ impl Solver for Day10 {
 }
 
 fn find_start_tile_type(grid: &Array2D<Tile>, start: Point2D) -> Result<Tile, ParseSolverError> {
-    let mut connections = [Point2D::ZERO, Point2D::ZERO];
+    let mut connections = [Direction2D::North; 2];
     let mut i = 0;
-    for dir in [Point2D::UP, Point2D::RIGHT, Point2D::DOWN, Point2D::LEFT] {
-        let neighbour_coords = start + dir;
+    for dir in Direction2D::ORTHOGONAL {
+        let neighbour_coords = start + dir.to_point();
         if let Some(neighbour) = grid.try_get(neighbour_coords) {
-            if neighbour.definition().contains_connection(dir.opposite()) {
+            if neighbour.definition().contains_connection(dir.reverse()) {
                 if i >= 2 {
                     return Err(ParseSolverError::new("found too many connections for start tile"));
                 }
@@ -105,13 +106,12 @@ fn find_start_tile_type(grid: &Array2D<Tile>, start: Point2D) -> Result<Tile, Pa
     }
 
     let tile = match connections {
-        [Point2D::ZERO,  Point2D::ZERO] => Tile::Empty,
-        [Point2D::UP,    Point2D::DOWN]  | [Point2D::DOWN,  Point2D::UP]    => Tile::NorthSouth,
-        [Point2D::RIGHT, Point2D::LEFT]  | [Point2D::LEFT,  Point2D::RIGHT] => Tile::EastWeast,
-        [Point2D::UP,    Point2D::RIGHT] | [Point2D::RIGHT, Point2D::UP]    => Tile::NorthEast,
-        [Point2D::UP,    Point2D::LEFT]  | [Point2D::LEFT,  Point2D::UP]    => Tile::NorthWest,
-        [Point2D::DOWN,  Point2D::LEFT]  | [Point2D::LEFT,  Point2D::DOWN]  => Tile::SouthWest,
-        [Point2D::DOWN,  Point2D::RIGHT] | [Point2D::RIGHT, Point2D::DOWN]  => Tile::SouthEast,
+        [Direction2D::North, Direction2D::South] | [Direction2D::South, Direction2D::North] => Tile::NorthSouth,
+        [Direction2D::East,  Direction2D::West]  | [Direction2D::West,  Direction2D::East]  => Tile::EastWeast,
+        [Direction2D::North, Direction2D::East]  | [Direction2D::East,  Direction2D::North] => Tile::NorthEast,
+        [Direction2D::North, Direction2D::West]  | [Direction2D::West,  Direction2D::North] => Tile::NorthWest,
+        [Direction2D::South, Direction2D::West]  | [Direction2D::West,  Direction2D::South] => Tile::SouthWest,
+        [Direction2D::South, Direction2D::East]  | [Direction2D::East,  Direction2D::South] => Tile::SouthEast,
         _ => return Err(ParseSolverError::new(format!("tile not found for connections: {connections:?}"))),
     };
 
@@ -120,7 +120,7 @@ fn find_start_tile_type(grid: &Array2D<Tile>, start: Point2D) -> Result<Tile, Pa
 
 fn run_loop(grid: &Array2D<Tile>, start: Point2D, mut loop_grid: Option<&mut Array2D<Tile>>) -> i32 {
     let mut current_coords = start;
-    let mut from = grid[start].definition().connections.unwrap()[0].opposite();
+    let mut from = grid[start].definition().connections.unwrap()[0].reverse();
     let mut distance = 0;
 
     loop {
@@ -132,7 +132,7 @@ fn run_loop(grid: &Array2D<Tile>, start: Point2D, mut loop_grid: Option<&mut Arr
             loop_grid[current_coords] = current_tile;
         }
 
-        current_coords = current_coords + next_dir;
+        current_coords = current_coords + next_dir.to_point();
         from = next_dir;
         distance += 1;
 
@@ -158,12 +158,12 @@ enum Tile {
 
 static DEFINITIONS: [TileDefinition; 7] = [
     TileDefinition::new(None),
-    TileDefinition::new(Some([Point2D::UP,    Point2D::DOWN])),
-    TileDefinition::new(Some([Point2D::RIGHT, Point2D::LEFT])),
-    TileDefinition::new(Some([Point2D::UP,    Point2D::RIGHT])),
-    TileDefinition::new(Some([Point2D::UP,    Point2D::LEFT])),
-    TileDefinition::new(Some([Point2D::DOWN,  Point2D::LEFT])),
-    TileDefinition::new(Some([Point2D::DOWN,  Point2D::RIGHT])),
+    TileDefinition::new(Some([Direction2D::North, Direction2D::South])),
+    TileDefinition::new(Some([Direction2D::East,  Direction2D::West])),
+    TileDefinition::new(Some([Direction2D::North, Direction2D::East])),
+    TileDefinition::new(Some([Direction2D::North, Direction2D::West])),
+    TileDefinition::new(Some([Direction2D::South, Direction2D::West])),
+    TileDefinition::new(Some([Direction2D::South, Direction2D::East])),
 ];
 
 impl Tile {
@@ -186,24 +186,24 @@ impl Tile {
 }
 
 struct TileDefinition {
-    connections: Option<[Point2D; 2]>,
+    connections: Option<[Direction2D; 2]>,
 }
 
 impl TileDefinition {
-    const fn new(connections: Option<[Point2D; 2]>) -> TileDefinition {
+    const fn new(connections: Option<[Direction2D; 2]>) -> TileDefinition {
         TileDefinition { connections }
     }
 
-    fn contains_connection(&self, dir: Point2D) -> bool {
+    fn contains_connection(&self, dir: Direction2D) -> bool {
         if let Some(connections) = &self.connections {
             return connections[0] == dir || connections[1] == dir;
         }
         false
     }
 
-    fn get_next_dir(&self, from: Point2D) -> Point2D {
+    fn get_next_dir(&self, from: Direction2D) -> Direction2D {
         let connections = self.connections.unwrap();
-        if from.opposite() == connections[0] {
+        if from.reverse() == connections[0] {
             connections[1]
         } else {
             connections[0]
@@ -216,7 +216,7 @@ enum ParseAreaState {
     Outside,
     OnLoop {
         was_inside: bool,
-        enter_direction: Point2D
+        enter_direction: Direction2D
     },
     Inside,
 }
@@ -225,76 +225,75 @@ use ParseAreaState::Outside;
 use ParseAreaState::OnLoop;
 use ParseAreaState::Inside;
 
+static TEST_INPUT_1A: &str = indoc!{
+   "-L|F7
+    7S-7|
+    L|7||
+    -L-J|
+    L|-JF"
+};
+
+static TEST_INPUT_1B: &str = indoc!{
+   "7-F7-
+    .FJ|7
+    SJLL7
+    |F--J
+    LJ.LJ"
+};
+
+static TEST_INPUT_2A: &str = indoc!{
+   "...........
+    .S-------7.
+    .|F-----7|.
+    .||.....||.
+    .||.....||.
+    .|L-7.F-J|.
+    .|..|.|..|.
+    .L--J.L--J.
+    ..........."
+};
+
+static TEST_INPUT_2B: &str = indoc!{
+  "..........
+   .S------7.
+   .|F----7|.
+   .||....||.
+   .||....||.
+   .|L-7F-J|.
+   .|..||..|.
+   .L--JL--J.
+   .........."
+};
+
+static TEST_INPUT_2C: &str = indoc!{
+ ".F----7F7F7F7F-7....
+  .|F--7||||||||FJ....
+  .||.FJ||||||||L7....
+  FJL7L7LJLJ||LJ.L-7..
+  L--J.L7...LJS7F-7L7.
+  ....F-J..F7FJ|L7L7L7
+  ....L7.F7||L7|.L7L7|
+  .....|FJLJ|FJ|F7|.LJ
+  ....FJL-7.||.||||...
+  ....L---J.LJ.LJLJ..."
+};
+
+static TEST_INPUT_2D: &str = indoc!{
+  "FF7FSF7F7F7F7F7F---7
+   L|LJ||||||||||||F--J
+   FL-7LJLJ||||||LJL-77
+   F--JF--7||LJLJ7F7FJ-
+   L---JF-JLJ.||-FJLJJ7
+   |F|F-JF---7F7-L7L|7|
+   |FFJF7L7F-JF7|JL---7
+   7-L-JL7||F7|L7F-7F7|
+   L.L7LFJ|||||FJL7||LJ
+   L7JLJL-JLJLJL--JLJ.L"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT_1A: &str = indoc!{
-       "-L|F7
-        7S-7|
-        L|7||
-        -L-J|
-        L|-JF"
-    };
-
-    static TEST_INPUT_1B: &str = indoc!{
-       "7-F7-
-        .FJ|7
-        SJLL7
-        |F--J
-        LJ.LJ"
-    };
-
-    static TEST_INPUT_2A: &str = indoc!{
-       "...........
-        .S-------7.
-        .|F-----7|.
-        .||.....||.
-        .||.....||.
-        .|L-7.F-J|.
-        .|..|.|..|.
-        .L--J.L--J.
-        ..........."
-     };
-
-     static TEST_INPUT_2B: &str = indoc!{
-       "..........
-        .S------7.
-        .|F----7|.
-        .||....||.
-        .||....||.
-        .|L-7F-J|.
-        .|..||..|.
-        .L--JL--J.
-        .........."
-      };
- 
-      static TEST_INPUT_2C: &str = indoc!{
-       ".F----7F7F7F7F-7....
-        .|F--7||||||||FJ....
-        .||.FJ||||||||L7....
-        FJL7L7LJLJ||LJ.L-7..
-        L--J.L7...LJS7F-7L7.
-        ....F-J..F7FJ|L7L7L7
-        ....L7.F7||L7|.L7L7|
-        .....|FJLJ|FJ|F7|.LJ
-        ....FJL-7.||.||||...
-        ....L---J.LJ.LJLJ..."
-     };
-
-     static TEST_INPUT_2D: &str = indoc!{
-       "FF7FSF7F7F7F7F7F---7
-        L|LJ||||||||||||F--J
-        FL-7LJLJ||||||LJL-77
-        F--JF--7||LJLJ7F7FJ-
-        L---JF-JLJ.||-FJLJJ7
-        |F|F-JF---7F7-L7L|7|
-        |FFJF7L7F-JF7|JL---7
-        7-L-JL7||F7|L7F-7F7|
-        L.L7LFJ|||||FJL7||LJ
-        L7JLJL-JLJLJL--JLJ.L"
-      };
 
     #[test]
     fn test() {
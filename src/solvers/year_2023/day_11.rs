@@ -17,6 +17,7 @@ impl FromStr for Day11 {
 
 impl Solver for Day11 {
     const INPUT_PATH: &'static str = "inputs/2023/11.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         self.sum_distances(2).into()
@@ -75,23 +76,22 @@ impl Day11 {
     }
 }
 
+static TEST_INPUT: &str = indoc!{
+   "...#......
+    .......#..
+    #.........
+    ..........
+    ......#...
+    .#........
+    .........#
+    ..........
+    .......#..
+    #...#....."
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "...#......
-        .......#..
-        #.........
-        ..........
-        ......#...
-        .#........
-        .........#
-        ..........
-        .......#..
-        #...#....."
-    };
 
     #[test]
     fn test() {
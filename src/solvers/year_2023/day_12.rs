@@ -1,7 +1,5 @@
 use std::collections::HashMap;
 
-use itertools::Itertools;
-
 use crate::solvers::prelude::*;
 
 pub struct Day12 {
@@ -12,15 +10,14 @@ impl FromStr for Day12 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let rows = s.lines()
-            .map(|line| line.parse::<Row>())
-            .try_collect()?;
+        let rows = parse_lines(s, Row::parse)?;
         Ok(Day12 { rows })
     }
 }
 
 impl Solver for Day12 {
     const INPUT_PATH: &'static str = "inputs/2023/12.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         self.rows.iter()
@@ -77,7 +74,7 @@ impl Row {
             remaining_size_min += remaining_groups.len() - 1;
         }
 
-        let group_size_max = self.condition_records.len() - index - remaining_size_min;
+        let group_size_max = (self.condition_records.len() - index).saturating_sub(remaining_size_min);
         if group_size_max == 0 {
             return 0;
         }
@@ -134,15 +131,14 @@ impl Row {
     }
 }
 
-impl FromStr for Row {
-    type Err = ParseSolverError;
-
-    fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let split = s.split_once(' ').ok_or(parse_solver_error!("fail to split row: {s}"))?;
-        let condition_records = split.0.chars().collect();
-        let contiguous_groups = split.1.split(',')
-            .map(|s| s.parse::<usize>())
-            .try_collect()?;
+impl Row {
+    fn parse(line: &mut LineCursor) -> Result<Row, ParseSolverError> {
+        let condition_records = line.parse_next_with(|token| match token.chars().find(|c| !matches!(c, '.' | '#' | '?')) {
+            Some(c) => Err(parse_solver_error!("invalid condition: {c}")),
+            None => Ok(token.chars().collect()),
+        })?;
+        let contiguous_groups = line.parse_separated(",")?;
+        line.finish()?;
         Ok(Row {
             condition_records,
             contiguous_groups,
@@ -150,19 +146,18 @@ impl FromStr for Row {
     }
 }
 
+static TEST_INPUT: &str = indoc!{
+   "???.### 1,1,3
+    .??..??...?##. 1,1,3
+    ?#?#?#?#?#?#?#? 1,3,1,6
+    ????.#...#... 4,1,1
+    ????.######..#####. 1,6,5
+    ?###???????? 3,2,1"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "???.### 1,1,3
-        .??..??...?##. 1,1,3
-        ?#?#?#?#?#?#?#? 1,3,1,6
-        ????.#...#... 4,1,1
-        ????.######..#####. 1,6,5
-        ?###???????? 3,2,1"
-    };
 
     #[test]
     fn test() {
@@ -1,6 +1,7 @@
 use itertools::Itertools;
 
 use crate::solvers::prelude::*;
+use crate::utils::Array2D;
 
 pub struct Day13 {
     patterns: Vec<Pattern>
@@ -10,9 +11,9 @@ impl FromStr for Day13 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let delimiter = if s.contains('\r') { "\r\n\r\n" } else { "\n\n" };
-        let patterns = s.split(delimiter)
-            .map(|pattern| pattern.parse())
+        let patterns = sections(s)
+            .iter()
+            .map(|section| section.parse(Pattern::parse))
             .try_collect()?;
         Ok(Day13 { patterns })
     }
@@ -20,6 +21,7 @@ impl FromStr for Day13 {
 
 impl Solver for Day13 {
     const INPUT_PATH: &'static str = "inputs/2023/13.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         self.patterns
@@ -43,27 +45,28 @@ struct Pattern {
     rows: Vec<u64>,
 }
 
-impl FromStr for Pattern {
-    type Err = ParseSolverError;
-
-    fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let width = s.lines().next().ok_or(ParseSolverError::new("fail to parse array width"))?.chars().count();
-        let mut columns = Vec::with_capacity(width);
-        let mut rows = Vec::new();
-
-        for _ in 0..width {
-            columns.push(0);
+impl Pattern {
+    fn parse(s: &str) -> Result<Pattern, ParseSolverError> {
+        let grid = Array2D::from_str_map(s, false, |_, c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseSolverError::with_kind(ParseErrorKind::InvalidValue, format!("invalid char: {c}"))),
+        })?;
+
+        // Rows and columns are stored as bitsets
+        if grid.width() > 64 || grid.height() > 64 {
+            return Err(parse_solver_error!("pattern larger than 64x64"));
         }
 
-        for (y, line) in s.lines().enumerate() {
-            let mut row = 0;
-            for (x, c) in line.chars().enumerate() {
-                if c == '#' {
-                    row |= 1 << x;
-                    *columns.get_mut(x).unwrap() |= 1 << y;
+        let mut columns = vec![0; grid.width() as usize];
+        let mut rows = vec![0; grid.height() as usize];
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                if grid[[x, y]] {
+                    rows[y as usize] |= 1 << x;
+                    columns[x as usize] |= 1 << y;
                 }
             }
-            rows.push(row);
         }
 
         Ok(Pattern {
@@ -71,9 +74,7 @@ impl FromStr for Pattern {
             rows,
         })
     }
-}
 
-impl Pattern {
     fn summarize(&self, look_for_smudge: bool) -> u64 {
         if let Some(lines) = find_reflection(&self.columns, look_for_smudge) {
             return lines;
@@ -112,28 +113,27 @@ fn find_smudge(left: u64, right: u64) -> bool {
     diff != 0 && (diff & (diff - 1)) == 0
 }
 
+static TEST_INPUT: &str = indoc!{
+   "#.##..##.
+    ..#.##.#.
+    ##......#
+    ##......#
+    ..#.##.#.
+    ..##..##.
+    #.#.##.#.
+    
+    #...##..#
+    #....#..#
+    ..##..###
+    #####.##.
+    #####.##.
+    ..##..###
+    #....#..#"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "#.##..##.
-        ..#.##.#.
-        ##......#
-        ##......#
-        ..#.##.#.
-        ..##..##.
-        #.#.##.#.
-        
-        #...##..#
-        #....#..#
-        ..##..###
-        #####.##.
-        #####.##.
-        ..##..###
-        #....#..#"
-    };
 
     #[test]
     fn test() {
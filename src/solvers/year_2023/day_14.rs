@@ -1,7 +1,6 @@
-use std::collections::HashMap;
-
 use crate::solvers::prelude::*;
 use crate::utils::Array2D;
+use crate::utils::cycle;
 
 pub struct Day14 {
     grid: Array2D<Tile>
@@ -18,6 +17,7 @@ impl FromStr for Day14 {
 
 impl Solver for Day14 {
     const INPUT_PATH: &'static str = "inputs/2023/14.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut grid = self.grid.clone();
@@ -26,42 +26,56 @@ impl Solver for Day14 {
 
     fn run_part2(&self) -> SolverResult {
         const CYCLE_COUNT: usize = 1_000_000_000;
-        const SAMPLE_SIZE: usize = 4;
-        const MAX_SIMULATION_CYCLES: usize = 1000;
 
+        // States are stored as bitsets of the rounded rocks, the grid is only used to simulate a step
         let mut grid = self.grid.clone();
-        let mut load_history = Vec::new();
-        load_history.push((0, 0)); // Just so that the index match the cycle number
-        let mut sample_map: HashMap<[(i64, i64); SAMPLE_SIZE], usize> = HashMap::new();
-
-        loop {
-            let loads = cycle(&mut grid);
-            load_history.push(loads);
-            if load_history.len() >= SAMPLE_SIZE {
-                let sample_start = load_history.len() - SAMPLE_SIZE;
-                let sample = load_history[sample_start..].try_into().unwrap();
-                if let Some(repeating_start) = sample_map.get(&sample) {
-                    let repeating_length = load_history.len() - repeating_start;
-                    let index = ((CYCLE_COUNT - repeating_start) % repeating_length) + repeating_start;
-                    return load_history[index].1.into();
-                }
-                sample_map.insert(sample, load_history.len());
-            }
-            if load_history.len() >= MAX_SIMULATION_CYCLES {
-                panic!("repeating sequence not found after {MAX_SIMULATION_CYCLES} cycles");
-            }
-        }
+        let rounded_rocks = cycle::state_after(get_rounded_rocks(&grid), |rounded_rocks| {
+            set_rounded_rocks(&mut grid, rounded_rocks);
+            spin_cycle(&mut grid);
+            get_rounded_rocks(&grid)
+        }, CYCLE_COUNT);
+        set_rounded_rocks(&mut grid, &rounded_rocks);
+        north_load(&grid).into()
     }
 }
 
-fn cycle(grid: &mut Array2D<Tile>) -> (i64, i64) {
+fn spin_cycle(grid: &mut Array2D<Tile>) {
     let load_north = tilt_north(grid);
     let load_west  = tilt_west (grid);
     let load_south = tilt_south(grid);
     let load_east  = tilt_east (grid);
     debug_assert_eq!(load_north, load_west);
     debug_assert_eq!(load_south, load_east);
-    (load_north, load_south)
+}
+
+fn get_rounded_rocks(grid: &Array2D<Tile>) -> Vec<u64> {
+    let mut rounded_rocks = vec![0; grid.iter().len().div_ceil(64)];
+    for (index, tile) in grid.iter().enumerate() {
+        if *tile == Tile::RoundedRock {
+            rounded_rocks[index / 64] |= 1 << (index % 64);
+        }
+    }
+    rounded_rocks
+}
+
+fn set_rounded_rocks(grid: &mut Array2D<Tile>, rounded_rocks: &[u64]) {
+    for (index, tile) in grid.iter_mut().enumerate() {
+        if *tile != Tile::CubeShapedRock {
+            *tile = if rounded_rocks[index / 64] & (1 << (index % 64)) != 0 { Tile::RoundedRock } else { Tile::Empty };
+        }
+    }
+}
+
+fn north_load(grid: &Array2D<Tile>) -> i64 {
+    let mut load = 0;
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            if grid[[x, y]] == Tile::RoundedRock {
+                load += grid.height() - y;
+            }
+        }
+    }
+    load
 }
 
 fn tilt_north(grid: &mut Array2D<Tile>) -> i64 {
@@ -196,23 +210,22 @@ impl TryFrom<char> for Tile {
     }
 }
 
+static TEST_INPUT: &str = indoc!{
+   "O....#....
+    O.OO#....#
+    .....##...
+    OO.#O....O
+    .O.....O#.
+    O.#..O.#.#
+    ..O..#O..O
+    .......O..
+    #....###..
+    #OO..#...."
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "O....#....
-        O.OO#....#
-        .....##...
-        OO.#O....O
-        .O.....O#.
-        O.#..O.#.#
-        ..O..#O..O
-        .......O..
-        #....###..
-        #OO..#...."
-    };
 
     #[test]
     fn test() {
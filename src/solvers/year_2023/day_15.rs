@@ -1,24 +1,26 @@
 use crate::solvers::prelude::*;
 
 pub struct Day15 {
-    initialization_sequence: String
+    initialization_sequence: Vec<Step>
 }
 
 impl FromStr for Day15 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        Ok(Day15 { initialization_sequence: s.to_string() })
+        let initialization_sequence = parse_line(s, |line| line.parse_separated(","))?;
+        Ok(Day15 { initialization_sequence })
     }
 }
 
 impl Solver for Day15 {
     const INPUT_PATH: &'static str = "inputs/2023/15.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         self.initialization_sequence
-            .split(',')
-            .map(hash)
+            .iter()
+            .map(|step| hash(&step.text))
             .sum::<usize>()
             .into()
     }
@@ -30,8 +32,8 @@ impl Solver for Day15 {
                 .collect::<Vec<_>>();
 
         self.initialization_sequence
-            .split(',')
-            .map(Instruction::from_str)
+            .iter()
+            .map(Instruction::from_step)
             .for_each(|instruction| instruction.execute(&mut boxes));
 
         boxes.iter()
@@ -41,6 +43,37 @@ impl Solver for Day15 {
     }
 }
 
+struct Step {
+    text: String,
+    label_len: usize,
+    operation: Operation,
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let (label, operation) = if let Some(label) = s.strip_suffix('-') {
+            (label, Operation::Remove)
+        } else if let Some((label, focal_length)) = s.split_once('=') {
+            let focal_length = focal_length.parse().map_err(|err| format!("invalid focal length: {err}"))?;
+            (label, Operation::Add(focal_length))
+        } else {
+            return Err("expected `-` or `=`".to_string());
+        };
+
+        if label.is_empty() {
+            return Err("empty label".to_string());
+        }
+
+        Ok(Step {
+            text: s.to_string(),
+            label_len: label.len(),
+            operation,
+        })
+    }
+}
+
 struct Instruction<'a> {
     box_id: usize,
     label: &'a str,
@@ -48,18 +81,12 @@ struct Instruction<'a> {
 }
 
 impl<'a> Instruction<'a> {
-    fn from_str(s: &'a str) -> Self {
-        let (label, operation) = if s.ends_with('-') {
-            (&s[..s.len() - 1], Operation::Remove)
-        } else {
-            let split = s.split_once('=').unwrap();
-            (split.0, Operation::Add(split.1.parse().unwrap()))
-        };
-
+    fn from_step(step: &'a Step) -> Self {
+        let label = &step.text[..step.label_len];
         Instruction {
             box_id: hash(label),
             label,
-            operation
+            operation: step.operation
         }
     }
 }
@@ -83,6 +110,7 @@ impl<'a> Instruction<'a> {
     }
 }
 
+#[derive(Clone, Copy)]
 enum Operation {
     Remove,
     Add(u32),
@@ -122,14 +150,13 @@ fn hash(s: &str) -> usize {
         .unwrap()
 }
 
+static TEST_INPUT: &str = indoc!{
+    "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-        "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"
-    };
 
     #[test]
     fn test() {
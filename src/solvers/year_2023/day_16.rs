@@ -1,6 +1,5 @@
 use crate::solvers::prelude::*;
-use crate::utils::Array2D;
-use crate::utils::Point2D;
+use crate::utils::{Array2D, Direction2D, Point2D};
 
 pub struct Day16 {
     grid: Array2D<char>
@@ -10,17 +9,22 @@ impl FromStr for Day16 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        Ok(Day16 {
-            grid: s.parse()?
-        })
+        // North is up in the input
+        let grid = Array2D::from_str_map(s, true, |_, c| match c {
+            '.' | '/' | '\\' | '|' | '-' => Ok(c),
+            _ => Err(ParseSolverError::with_kind(ParseErrorKind::InvalidValue, format!("invalid char: {c}"))),
+        })?;
+        Ok(Day16 { grid })
     }
 }
 
 impl Solver for Day16 {
     const INPUT_PATH: &'static str = "inputs/2023/16.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
-        self.simulate_beam(Point2D::ZERO, Point2D::RIGHT).into()
+        let top = self.grid.height() - 1;
+        self.simulate_beam(Point2D::new(0, top), Direction2D::East).into()
     }
 
     fn run_part2(&self) -> SolverResult {
@@ -29,18 +33,18 @@ impl Solver for Day16 {
         let mut max = 0;
 
         for x in 0..self.grid.width() {
-            let energized_tiles = self.simulate_beam(Point2D::new(x, 0), Point2D::UP);
+            let energized_tiles = self.simulate_beam(Point2D::new(x, 0), Direction2D::North);
             max = i32::max(energized_tiles, max);
 
-            let energized_tiles = self.simulate_beam(Point2D::new(x, last_y), Point2D::DOWN);
+            let energized_tiles = self.simulate_beam(Point2D::new(x, last_y), Direction2D::South);
             max = i32::max(energized_tiles, max);
         }
 
         for y in 0..self.grid.height() {
-            let energized_tiles = self.simulate_beam(Point2D::new(0, y), Point2D::RIGHT);
+            let energized_tiles = self.simulate_beam(Point2D::new(0, y), Direction2D::East);
             max = i32::max(energized_tiles, max);
 
-            let energized_tiles = self.simulate_beam(Point2D::new(last_x, y), Point2D::LEFT);
+            let energized_tiles = self.simulate_beam(Point2D::new(last_x, y), Direction2D::West);
             max = i32::max(energized_tiles, max);
         }
 
@@ -49,8 +53,8 @@ impl Solver for Day16 {
 }
 
 impl Day16 {
-    fn simulate_beam(&self, beam_pos: Point2D, beam_dir: Point2D) -> i32 {
-        debug_assert!(beam_dir.is_unit());
+    fn simulate_beam(&self, beam_pos: Point2D, beam_dir: Direction2D) -> i32 {
+        debug_assert!(!beam_dir.is_diagonal());
 
         let mut beam_grid: Array2D<BeamTile> = Array2D::new(self.grid.sizes());
         let mut energized_tiles = 0;
@@ -58,13 +62,8 @@ impl Day16 {
 
         while let Some(mut beam) = beams_queue.pop() {
             while let Some(beam_tile) = beam_grid.try_get_mut(beam.pos) {
-                let dir_mask = match beam.dir {
-                    Point2D::RIGHT => BeamTile::RIGHT_MASK,
-                    Point2D::LEFT  => BeamTile::LEFT_MASK,
-                    Point2D::UP    => BeamTile::UP_MASK,
-                    Point2D::DOWN  => BeamTile::DOWN_MASK,
-                    dir  => panic!("invalid dir: {dir}"),
-                };
+                // Orthogonal directions are every other compass direction
+                let dir_mask = 1 << (beam.dir as usize / 2);
 
                 if beam_tile.get(dir_mask) {
                     break;
@@ -77,25 +76,22 @@ impl Day16 {
                 beam_tile.set(dir_mask);
 
                 match self.grid[beam.pos] {
-                    '.'  => {}
-                    '/'  => beam.dir = Point2D::new(-beam.dir.y(), -beam.dir.x()),
-                    '\\' => beam.dir = Point2D::new(beam.dir.y(), beam.dir.x()),
-                    '|'  => {
-                        if beam.dir.is_horizontal() {
-                            beam.dir = Point2D::UP;
-                            beams_queue.push(Beam::new(beam.pos + Point2D::DOWN, Point2D::DOWN));
-                        }
+                    '/'  => beam.dir = if beam.dir.is_horizontal() { beam.dir.turn_left() } else { beam.dir.turn_right() },
+                    '\\' => beam.dir = if beam.dir.is_horizontal() { beam.dir.turn_right() } else { beam.dir.turn_left() },
+                    '|' if beam.dir.is_horizontal() => {
+                        beam.dir = Direction2D::North;
+                        let split_dir = Direction2D::South;
+                        beams_queue.push(Beam::new(beam.pos + split_dir.to_point(), split_dir));
                     }
-                    '-'  => {
-                        if beam.dir.is_vertical() {
-                            beam.dir = Point2D::RIGHT;
-                            beams_queue.push(Beam::new(beam.pos + Point2D::LEFT, Point2D::LEFT));
-                        }
+                    '-' if beam.dir.is_vertical() => {
+                        beam.dir = Direction2D::East;
+                        let split_dir = Direction2D::West;
+                        beams_queue.push(Beam::new(beam.pos + split_dir.to_point(), split_dir));
                     }
-                    c => panic!("invalid char: {c}")
+                    _ => {}
                 }
 
-                beam.pos += beam.dir;
+                beam.pos += beam.dir.to_point();
             }
         }
 
@@ -105,11 +101,11 @@ impl Day16 {
 
 struct Beam {
     pos: Point2D,
-    dir: Point2D,
+    dir: Direction2D,
 }
 
 impl Beam {
-    fn new(pos: Point2D, dir: Point2D) -> Beam {
+    fn new(pos: Point2D, dir: Direction2D) -> Beam {
         Beam { pos, dir }
     }
 }
@@ -120,11 +116,6 @@ struct BeamTile {
 }
 
 impl BeamTile {
-    const RIGHT_MASK: u8 = 0b0000_0001;
-    const LEFT_MASK:  u8 = 0b0000_0010;
-    const UP_MASK:    u8 = 0b0000_0100;
-    const DOWN_MASK:  u8 = 0b0000_1000;
-
     fn empty(&self) -> bool {
         self.bits == 0
     }
@@ -138,23 +129,22 @@ impl BeamTile {
     }
 }
 
+static TEST_INPUT: &str = indoc!{
+  r".|...\....
+    |.-.\.....
+    .....|-...
+    ........|.
+    ..........
+    .........\
+    ..../.\\..
+    .-.-/..|..
+    .|....-|.\
+    ..//.|...."
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-      r".|...\....
-        |.-.\.....
-        .....|-...
-        ........|.
-        ..........
-        .........\
-        ..../.\\..
-        .-.-/..|..
-        .|....-|.\
-        ..//.|...."
-    };
 
     #[test]
     fn test() {
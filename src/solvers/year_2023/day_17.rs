@@ -1,10 +1,8 @@
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
-
 use crate::solvers::prelude::*;
 use crate::utils::Array2D;
-use crate::utils::ArrayMD;
+use crate::utils::Direction2D;
 use crate::utils::Point2D;
+use crate::utils::search;
 
 pub struct Day17 {
     heat_loss_map: Array2D<u32>
@@ -23,6 +21,7 @@ impl FromStr for Day17 {
 
 impl Solver for Day17 {
     const INPUT_PATH: &'static str = "inputs/2023/17.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT, TEST_INPUT_2B];
 
     fn run_part1(&self) -> SolverResult {
         let target = Point2D::new(self.heat_loss_map.width() - 1, self.heat_loss_map.height() - 1);
@@ -36,71 +35,60 @@ impl Solver for Day17 {
 }
 
 impl Day17 {
-    fn dijkstra(&self, start: Point2D, target: Point2D, min_consecutive_blocks: u32, max_consecutive_blocks: u32) -> u32 {
+    fn dijkstra(&self, start: Point2D, target: Point2D, min_consecutive_blocks: u32, max_consecutive_blocks: u32) -> u64 {
         let max_consecutive_blocks = max_consecutive_blocks - min_consecutive_blocks;
 
-        let close_set_sizes = [
-            self.heat_loss_map.width(),
-            self.heat_loss_map.height(),
-            4,
-            i64::from(max_consecutive_blocks) + 1,
-        ];
-        let mut close_set: ArrayMD<4, bool> = ArrayMD::new(close_set_sizes);
-
-        let mut open_set: BinaryHeap<OpenState> = BinaryHeap::new();
-        open_set.push(OpenState {
+        let start_state = State {
             pos: start,
-            dir: Direction::None,
+            dir: None,
             consecutive_blocks: max_consecutive_blocks, // Force turn at start
-            total_heat_loss: 0,
-        });
+        };
 
-        while let Some(state) = open_set.pop() {
+        let successors = |state: &State| {
             debug_assert!(state.consecutive_blocks <= max_consecutive_blocks);
 
-            let close_set_coords = [
-                state.pos.x(),
-                state.pos.y(),
-                i64::try_from(state.dir as usize).unwrap(),
-                i64::from(state.consecutive_blocks)
-            ];
-            
-            if state.dir != Direction::None && close_set[close_set_coords] {
-                continue;
-            }
-
-            if state.pos == target {
-                return state.total_heat_loss;
-            }
-
-            if state.consecutive_blocks < max_consecutive_blocks {
-                let new_pos = state.pos + state.dir.to_point();
+            let mut successors = Vec::with_capacity(3);
+            if let Some(dir) = state.dir.filter(|_| state.consecutive_blocks < max_consecutive_blocks) {
+                let new_pos = state.pos + dir.to_point();
                 if let Some(heat_loss) = self.heat_loss_map.try_get(new_pos) {
-                    open_set.push(OpenState {
+                    let new_state = State {
                         pos: new_pos,
                         dir: state.dir,
                         consecutive_blocks: state.consecutive_blocks + 1,
-                        total_heat_loss: state.total_heat_loss + heat_loss,
-                    })
+                    };
+                    successors.push((new_state, u64::from(*heat_loss)));
                 }
             }
 
-            for dir in get_turns(state.dir) {
-                if let Some(mut new_open_state) = self.move_forward(state.pos, *dir, min_consecutive_blocks) {
-                    new_open_state.total_heat_loss += state.total_heat_loss;
-                    open_set.push(new_open_state);
+            let turns = match state.dir {
+                Some(dir) => vec![dir.turn_left(), dir.turn_right()],
+                None => Direction2D::ORTHOGONAL.to_vec(),
+            };
+            for dir in turns {
+                if let Some(successor) = self.move_forward(state.pos, dir, min_consecutive_blocks) {
+                    successors.push(successor);
                 }
             }
-
-            if state.dir != Direction::None {
-                close_set[close_set_coords] = true;
-            }
-        }
-
-        panic!("fail to find a path between {start} and {target}");
+            successors
+        };
+
+        // Dense index over position, direction (or none) and consecutive blocks
+        let width = self.heat_loss_map.width() as usize;
+        let consecutive_block_count = max_consecutive_blocks as usize + 1;
+        let state_count = width * self.heat_loss_map.height() as usize * 5 * consecutive_block_count;
+        let index_of = |state: &State| {
+            let pos_index = state.pos.y() as usize * width + state.pos.x() as usize;
+            // Orthogonal directions are every other compass direction
+            let dir_index = state.dir.map_or(4, |dir| dir as usize / 2);
+            (pos_index * 5 + dir_index) * consecutive_block_count + state.consecutive_blocks as usize
+        };
+
+        search::dijkstra_indexed(start_state, state_count, index_of, successors, |state| state.pos == target)
+            .unwrap_or_else(|| panic!("fail to find a path between {start} and {target}"))
+            .cost
     }
 
-    fn move_forward(&self, pos: Point2D, dir: Direction, distance: u32) -> Option<OpenState> {
+    fn move_forward(&self, pos: Point2D, dir: Direction2D, distance: u32) -> Option<(State, u64)> {
         debug_assert!(distance > 0);
 
         let dir_point = dir.to_point();
@@ -114,100 +102,50 @@ impl Day17 {
             total_heat_loss += heat_loss;
         }
 
-        Some(OpenState {
+        let state = State {
             pos: new_pos,
-            dir,
+            dir: Some(dir),
             consecutive_blocks: 0,
-            total_heat_loss,
-        })
-    }
-}
-
-fn get_turns(dir: Direction) -> &'static [Direction] {
-    match dir {
-        Direction::Right | Direction::Left => &[Direction::Up,    Direction::Down],
-        Direction::Up    | Direction::Down => &[Direction::Right, Direction::Left],
-        Direction::None => &[Direction::Right, Direction::Left, Direction::Up, Direction::Down],
+        };
+        Some((state, u64::from(total_heat_loss)))
     }
 }
 
-#[derive(Clone, Copy, Eq, Hash, PartialEq)]
-enum Direction {
-    Right,
-    Left,
-    Up,
-    Down,
-    None,
-}
-
-impl Direction {
-    fn to_point(&self) -> Point2D {
-        match *self{
-            Direction::Right => Point2D::RIGHT,
-            Direction::Left => Point2D::LEFT,
-            Direction::Up => Point2D::UP,
-            Direction::Down => Point2D::DOWN,
-            Direction::None => panic!("to_point should not be call for Direction::None")
-        }
-    }
-}
-
-#[derive(Eq)]
-struct OpenState {
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct State {
     pos: Point2D,
-    dir: Direction,
+    // None at start, any direction can be taken
+    dir: Option<Direction2D>,
     consecutive_blocks: u32,
-    total_heat_loss: u32,
-}
-
-impl PartialEq for OpenState {
-    fn eq(&self, other: &Self) -> bool {
-        self.pos == other.pos &&
-        self.dir == other.dir &&
-        self.consecutive_blocks == other.consecutive_blocks
-    }
 }
 
-impl PartialOrd for OpenState {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for OpenState {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.total_heat_loss.cmp(&other.total_heat_loss).reverse()
-    }
-}
+static TEST_INPUT: &str = indoc!{
+   "2413432311323
+    3215453535623
+    3255245654254
+    3446585845452
+    4546657867536
+    1438598798454
+    4457876987766
+    3637877979653
+    4654967986887
+    4564679986453
+    1224686865563
+    2546548887735
+    4322674655533"
+};
+
+static TEST_INPUT_2B: &str = indoc!{
+   "111111111111
+    999999999991
+    999999999991
+    999999999991
+    999999999991"
+};
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "2413432311323
-        3215453535623
-        3255245654254
-        3446585845452
-        4546657867536
-        1438598798454
-        4457876987766
-        3637877979653
-        4654967986887
-        4564679986453
-        1224686865563
-        2546548887735
-        4322674655533"
-    };
-
-    static TEST_INPUT_2B: &str = indoc!{
-       "111111111111
-        999999999991
-        999999999991
-        999999999991
-        999999999991"
-     };
 
     #[test]
     fn test() {
@@ -1,8 +1,6 @@
 use crate::solvers::prelude::*;
 use crate::utils::Point2D;
 
-use itertools::Itertools;
-
 pub struct Day18 {
     instructions: Vec<Instruction>
 }
@@ -11,20 +9,18 @@ impl FromStr for Day18 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let instructions = s.lines()
-            .map(|instruction: &str| instruction.parse())
-            .try_collect()?;
-
+        let instructions = parse_lines(s, Instruction::parse)?;
         Ok(Day18 { instructions })
     }
 }
 
 impl Solver for Day18 {
     const INPUT_PATH: &'static str = "inputs/2023/18.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let instructions = self.instructions.iter()
-            .map(|instruction| (instruction.direction(), instruction.dist))
+            .map(|instruction| (instruction.dir, instruction.dist))
             .collect::<Vec<_>>();
 
         naive::run(instructions).into()
@@ -32,7 +28,7 @@ impl Solver for Day18 {
 
     fn run_part2(&self) -> SolverResult {
         let instructions = self.instructions.iter()
-            .map(Instruction::decode_color)
+            .map(|instruction| (instruction.color_dir, instruction.color_dist))
             .collect::<Vec<_>>();
 
         optim::run(instructions).into()
@@ -40,47 +36,47 @@ impl Solver for Day18 {
 }
 
 struct Instruction {
-    dir: char,
+    dir: Point2D,
     dist: i64,
-    color: String,
-}
-
-impl FromStr for Instruction {
-    type Err = ParseSolverError;
-
-    fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let mut split = s.split_whitespace();
-        Ok(Instruction {
-            dir: split.next().unwrap().chars().next().unwrap(),
-            dist: split.next().unwrap().parse()?,
-            color: split.next().unwrap().to_string(),
-        })
-    }
+    // Instruction decoded from the color
+    color_dir: Point2D,
+    color_dist: i64,
 }
 
 impl Instruction {
-    fn direction(&self) -> Point2D {
-        match self.dir {
-            'R' => Point2D::RIGHT,
-            'L' => Point2D::LEFT,
-            'U' => Point2D::UP,
-            'D' => Point2D::DOWN,
-            c => panic!("invalid char: {c}"),
-        }
-    }
+    fn parse(line: &mut LineCursor) -> Result<Instruction, ParseSolverError> {
+        let dir = line.parse_char(|c| match c {
+            'R' => Some(Point2D::RIGHT),
+            'L' => Some(Point2D::LEFT),
+            'U' => Some(Point2D::UP),
+            'D' => Some(Point2D::DOWN),
+            _ => None,
+        })?;
+        let dist = line.parse_next()?;
 
-    fn decode_color(&self) -> (Point2D, i64) {
-        let s = &self.color.as_str()[2..];
-        let dist = i64::from_str_radix(&s[..5], 16).unwrap();
-        let dir = s[5..6].chars().next().unwrap();
-        let dir = match dir {
-            '0' => Point2D::RIGHT,
-            '1' => Point2D::DOWN,
-            '2' => Point2D::LEFT,
-            '3' => Point2D::UP,
-            c => panic!("invalid char: {c}"),
+        line.expect("(#")?;
+        let position = line.position();
+        let color = line.take_while(|c| c.is_ascii_hexdigit());
+        if color.len() != 6 {
+            return Err(line.error_at(position, ParseErrorKind::InvalidValue, "expected a color of 6 hex digits"));
+        }
+        let color_dist = i64::from_str_radix(&color[..5], 16)?;
+        let color_dir = match &color[5..] {
+            "0" => Point2D::RIGHT,
+            "1" => Point2D::DOWN,
+            "2" => Point2D::LEFT,
+            "3" => Point2D::UP,
+            c => return Err(line.error_at(position + 5, ParseErrorKind::InvalidValue, format!("`{c}` is not a direction"))),
         };
-        (dir, dist)
+        line.expect(")")?;
+        line.finish()?;
+
+        Ok(Instruction {
+            dir,
+            dist,
+            color_dir,
+            color_dist,
+        })
     }
 }
 
@@ -319,27 +315,26 @@ mod optim {
     }
 }
 
+static TEST_INPUT: &str = indoc!{
+   "R 6 (#70c710)
+    D 5 (#0dc571)
+    L 2 (#5713f0)
+    D 2 (#d2c081)
+    R 2 (#59c680)
+    D 2 (#411b91)
+    L 5 (#8ceee2)
+    U 2 (#caa173)
+    L 1 (#1b58a2)
+    U 2 (#caa171)
+    R 2 (#7807d2)
+    U 3 (#a77fa3)
+    L 2 (#015232)
+    U 2 (#7a21e3)"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "R 6 (#70c710)
-        D 5 (#0dc571)
-        L 2 (#5713f0)
-        D 2 (#d2c081)
-        R 2 (#59c680)
-        D 2 (#411b91)
-        L 5 (#8ceee2)
-        U 2 (#caa173)
-        L 1 (#1b58a2)
-        U 2 (#caa171)
-        R 2 (#7807d2)
-        U 3 (#a77fa3)
-        L 2 (#015232)
-        U 2 (#7a21e3)"
-    };
 
     #[test]
     fn test() {
@@ -1,9 +1,7 @@
 use std::collections::HashMap;
 
 use crate::solvers::prelude::*;
-use crate::utils::BoundMD;
-
-use itertools::Itertools;
+use crate::utils::{IntervalSet, Range};
 
 pub struct Day19 {
     workflows: HashMap<String, Workflow>,
@@ -14,16 +12,24 @@ impl FromStr for Day19 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let delimiter = if s.contains('\r') { "\r\n\r\n" } else { "\n\n" };
-        let (workflows, parts) = s.split_once(delimiter).ok_or(parse_solver_error!("fail to split input"))?;
+        let [workflows_section, parts_section] = &sections(s)[..] else {
+            return Err(parse_solver_error!("expected workflows and parts separated by an empty line"));
+        };
+
+        let workflows: HashMap<String, Workflow> = workflows_section.parse_lines(parse_workflow)?
+            .into_iter()
+            .collect();
 
-        let workflows = workflows.lines()
-            .map(parse_workflow)
-            .try_collect()?;
+        if !workflows.contains_key("in") {
+            return Err(parse_solver_error!("workflow `in` not found"));
+        }
+        for (name, workflow) in workflows.iter() {
+            if let Some(rule) = workflow.rules.iter().find(|rule| !matches!(rule.dst.as_str(), "A" | "R") && !workflows.contains_key(&rule.dst)) {
+                return Err(parse_solver_error!("workflow `{name}` sends to unknown workflow `{}`", rule.dst));
+            }
+        }
 
-        let parts = parts.lines()
-            .map(parse_part)
-            .try_collect()?;
+        let parts = parts_section.parse_lines(parse_part)?;
 
         Ok(Day19 {
             workflows,
@@ -34,6 +40,7 @@ impl FromStr for Day19 {
 
 impl Solver for Day19 {
     const INPUT_PATH: &'static str = "inputs/2023/19.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let mut sum = 0;
@@ -47,12 +54,14 @@ impl Solver for Day19 {
     }
 
     fn run_part2(&self) -> SolverResult {
-        let mut queue = vec![(BoundMD::<4>::from_min_max(1, 4000), "in")];
+        // Ratings of each category that lead to the workflow
+        let ratings: [IntervalSet; 4] = std::array::from_fn(|_| IntervalSet::from(Range::from_min_max(1, 4000)));
+        let mut queue = vec![(ratings, "in")];
         let mut count = 0;
 
-        while let Some ((mut bound, dst)) = queue.pop() {
+        while let Some((mut ratings, dst)) = queue.pop() {
             if dst == "A" {
-                count += bound.volume();
+                count += ratings.iter().map(IntervalSet::len).product::<i64>();
                 continue;
             } else if dst == "R" {
                 continue;
@@ -62,35 +71,30 @@ impl Solver for Day19 {
                 panic!("workflow '{dst}' not found");
             };
             for rule in workflow.rules.iter() {
-                match rule.condition {
+                let (rating_category, matching, remaining) = match rule.condition {
                     Condition::MoreThan{ rating_category, value } => {
-                        if bound.get_range_d(rating_category).contains(value) {
-                            let mut new_bound = bound;
-                            bound.set_max_d(rating_category, value);
-                            new_bound.set_min_d(rating_category, value + 1);
-                            if new_bound.volume() > 0 {
-                                queue.push((new_bound, rule.dst.as_str()));
-                            }
-                        }
+                        let (lower, upper) = ratings[rating_category].split_at(value + 1);
+                        (rating_category, upper, lower)
                     },
                     Condition::LessThan{ rating_category, value } => {
-                        if bound.get_range_d(rating_category).contains(value) {
-                            let mut new_bound = bound;
-                            bound.set_min_d(rating_category, value);
-                            new_bound.set_max_d(rating_category, value - 1);
-                            if new_bound.volume() > 0 {
-                                queue.push((new_bound, rule.dst.as_str()));
-                            }
-                        }
+                        let (lower, upper) = ratings[rating_category].split_at(value);
+                        (rating_category, lower, upper)
                     },
                     Condition::None => {
-                        queue.push((bound, rule.dst.as_str()));
+                        queue.push((ratings, rule.dst.as_str()));
                         break;
                     },
+                };
+
+                if !matching.is_empty() {
+                    let mut matching_ratings = ratings.clone();
+                    matching_ratings[rating_category] = matching;
+                    queue.push((matching_ratings, rule.dst.as_str()));
                 }
-                if bound.volume() == 0 {
+                if remaining.is_empty() {
                     break;
                 }
+                ratings[rating_category] = remaining;
             }
         }
 
@@ -120,64 +124,84 @@ impl Day19 {
     }
 }
 
-fn parse_workflow(line: &str) -> Result<(String, Workflow), ParseSolverError> {
-    let (name, rules) = line[..(line.len()-1)]
-        .split_once('{')
-        .ok_or(parse_solver_error!("fail to split line: {line}"))?;
+fn parse_workflow(line: &mut LineCursor) -> Result<(String, Workflow), ParseSolverError> {
+    let name = line.take_while(char::is_alphabetic);
+    if name.is_empty() {
+        return Err(line.error(ParseErrorKind::InvalidValue, "expected a workflow name"));
+    }
+    line.expect("{")?;
+
+    let mut rules = vec![parse_rule(line)?];
+    while line.consume(",") {
+        rules.push(parse_rule(line)?);
+    }
+    line.expect("}")?;
+    line.finish()?;
 
-    let rules = rules.split(',')
-        .map(parse_rule)
-        .try_collect()?;
+    // The last rule always applies
+    if !matches!(rules.last(), Some(Rule { condition: Condition::None, .. })) {
+        return Err(line.error_at(0, ParseErrorKind::InvalidValue, "the last rule must not have a condition"));
+    }
 
     Ok(( name.to_string(), Workflow { rules } ))
 }
 
-fn parse_rule(line: &str) -> Result<Rule, ParseSolverError> {
-    if let Some((condition, dst)) = line.split_once(':') {
-        let condition = if let Some((rating_category, value)) = condition.split_once('>') {
-            let rating_category = parse_rating_category(rating_category)?;
-            let value = value.parse::<i64>()?;
-            Condition::MoreThan{ rating_category, value }
-        } else if let Some((rating_category, value)) = condition.split_once('<') {
-            let rating_category = parse_rating_category(rating_category)?;
-            let value = value.parse::<i64>()?;
-            Condition::LessThan{ rating_category, value }
-        } else {
-            return Err(parse_solver_error!("fail to split condition: {condition}"));
-        };
-
+fn parse_rule(line: &mut LineCursor) -> Result<Rule, ParseSolverError> {
+    let position = line.position();
+    let token = line.take_while(char::is_alphabetic);
+    let is_more_than = line.consume(">");
+    if !is_more_than && !line.consume("<") {
+        if token.is_empty() {
+            return Err(line.error(ParseErrorKind::InvalidValue, "expected a workflow name"));
+        }
         return Ok(Rule {
-            condition,
-            dst: dst.to_string(),
+            condition: Condition::None,
+            dst: token.to_string(),
         });
     }
 
+    let rating_category = parse_rating_category(token)
+        .ok_or_else(|| line.error_at(position, ParseErrorKind::InvalidValue, format!("`{token}` is not a rating category")))?;
+    let value = line.parse_until(":")?;
+    let condition = if is_more_than {
+        Condition::MoreThan{ rating_category, value }
+    } else {
+        Condition::LessThan{ rating_category, value }
+    };
+
+    let dst = line.take_while(char::is_alphabetic);
+    if dst.is_empty() {
+        return Err(line.error(ParseErrorKind::InvalidValue, "expected a workflow name"));
+    }
+
     Ok(Rule {
-        condition: Condition::None,
-        dst: line.to_string(),
+        condition,
+        dst: dst.to_string(),
     })
 }
 
-fn parse_rating_category(s: &str) -> Result<usize, ParseSolverError> {
-    let c = s.chars().next().ok_or(parse_solver_error!("fail to get char"))?;
-    Ok(match c {
-        'x' => 0,
-        'm' => 1,
-        'a' => 2,
-        's' => 3,
-        _ => return Err(parse_solver_error!("invalid char: {c}")),
+fn parse_rating_category(s: &str) -> Option<usize> {
+    Some(match s {
+        "x" => 0,
+        "m" => 1,
+        "a" => 2,
+        "s" => 3,
+        _ => return None,
     })
 }
 
-fn parse_part(line: &str) -> Result<[i64; 4], ParseSolverError> {
-    let line = &line[1..(line.len()-1)];
-    let mut split = line.split(",");
-    Ok([
-        split.next().ok_or(parse_solver_error!("fail to parse part: {line}"))?[2..].parse()?,
-        split.next().ok_or(parse_solver_error!("fail to parse part: {line}"))?[2..].parse()?,
-        split.next().ok_or(parse_solver_error!("fail to parse part: {line}"))?[2..].parse()?,
-        split.next().ok_or(parse_solver_error!("fail to parse part: {line}"))?[2..].parse()?,
-    ])
+fn parse_part(line: &mut LineCursor) -> Result<[i64; 4], ParseSolverError> {
+    line.expect("{")?;
+    line.expect("x=")?;
+    let x = line.parse_until(",")?;
+    line.expect("m=")?;
+    let m = line.parse_until(",")?;
+    line.expect("a=")?;
+    let a = line.parse_until(",")?;
+    line.expect("s=")?;
+    let s = line.parse_until("}")?;
+    line.finish()?;
+    Ok([x, m, a, s])
 }
 
 struct Workflow {
@@ -211,30 +235,29 @@ enum Condition {
     None,
 }
 
+static TEST_INPUT: &str = indoc!{
+   "px{a<2006:qkq,m>2090:A,rfg}
+    pv{a>1716:R,A}
+    lnx{m>1548:A,A}
+    rfg{s<537:gd,x>2440:R,A}
+    qs{s>3448:A,lnx}
+    qkq{x<1416:A,crn}
+    crn{x>2662:A,R}
+    in{s<1351:px,qqz}
+    qqz{s>2770:qs,m<1801:hdj,R}
+    gd{a>3333:R,R}
+    hdj{m>838:A,pv}
+    
+    {x=787,m=2655,a=1222,s=2876}
+    {x=1679,m=44,a=2067,s=496}
+    {x=2036,m=264,a=79,s=2244}
+    {x=2461,m=1339,a=466,s=291}
+    {x=2127,m=1623,a=2188,s=1013}"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "px{a<2006:qkq,m>2090:A,rfg}
-        pv{a>1716:R,A}
-        lnx{m>1548:A,A}
-        rfg{s<537:gd,x>2440:R,A}
-        qs{s>3448:A,lnx}
-        qkq{x<1416:A,crn}
-        crn{x>2662:A,R}
-        in{s<1351:px,qqz}
-        qqz{s>2770:qs,m<1801:hdj,R}
-        gd{a>3333:R,R}
-        hdj{m>838:A,pv}
-        
-        {x=787,m=2655,a=1222,s=2876}
-        {x=1679,m=44,a=2067,s=496}
-        {x=2036,m=264,a=79,s=2244}
-        {x=2461,m=1339,a=466,s=291}
-        {x=2127,m=1623,a=2188,s=1013}"
-    };
 
     #[test]
     fn test() {
@@ -1,6 +1,7 @@
 use std::collections::{HashMap, VecDeque};
 
 use crate::solvers::prelude::*;
+use crate::utils::graph::{Graph, GraphStyle};
 
 use itertools::Itertools;
 use num::integer;
@@ -15,9 +16,14 @@ impl FromStr for Day20 {
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
         let mut module_names = vec!["button".to_string()];
-        let mut modules: HashMap<usize, Module> = s.lines()
-            .map(|line| parse_module(line, &mut module_names).and_then(|module| Ok((module.id, module))))
-            .try_collect()?;
+        let mut modules: HashMap<usize, Module> = parse_lines(s, |line| parse_module(line, &mut module_names))?
+            .into_iter()
+            .map(|module| (module.id, module))
+            .collect();
+
+        if get_module_id(&module_names, "broadcaster").is_none() {
+            return Err(parse_solver_error!("module `broadcaster` not found"));
+        }
 
         let mut module_inputs = vec![Vec::new(); module_names.len()];
         for (_, module) in modules.iter() {
@@ -44,6 +50,7 @@ impl FromStr for Day20 {
 
 impl Solver for Day20 {
     const INPUT_PATH: &'static str = "inputs/2023/20.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT_1A, TEST_INPUT_1B];
 
     fn run_part1(&self) -> SolverResult {
         let mut modules = self.modules.clone();
@@ -119,24 +126,52 @@ impl Solver for Day20 {
             .fold(1, |acc, (_, cycle)| integer::lcm(acc, *cycle))
             .into()
     }
+
+    fn export_graph(&self, format: GraphFormat) -> Option<String> {
+        let mut graph = Graph::new();
+        for name in self.module_names.iter() {
+            graph.create_node(name.clone());
+        }
+        for module in self.modules.values().sorted_by_key(|module| module.id) {
+            let prefix = match module.module_type {
+                ModuleType::FlipFlop(_) => "%",
+                ModuleType::Conjunction(_) => "&",
+                ModuleType::Broadcast => "",
+            };
+            *graph.get_node_mut(module.id).value_mut() = format!("{prefix}{}", self.module_names[module.id]);
+            for output in module.outputs.iter() {
+                graph.create_edge(module.id, *output, false, ());
+            }
+        }
+
+        let style = GraphStyle::with_value_labels();
+        Some(graph.export(format, &style))
+    }
 }
 
-fn parse_module(mut line: &str, module_names: &mut Vec<String>) -> Result<Module, ParseSolverError> {
-    let first_char = line.chars().next().ok_or(parse_solver_error!("fail to get first char for line: {line}"))?;
-    let module_type = if first_char == '%' {
-        line = &line[1..];
+fn parse_module(line: &mut LineCursor, module_names: &mut Vec<String>) -> Result<Module, ParseSolverError> {
+    let module_type = if line.consume("%") {
         ModuleType::FlipFlop(FlipFlop::default())
-    } else if first_char == '&' {
-        line = &line[1..];
+    } else if line.consume("&") {
         ModuleType::Conjunction(Conjunction::default())
     } else {
         ModuleType::Broadcast
     };
 
-    let split = line.split_once(" -> ").ok_or(parse_solver_error!("fail to split line: {line}"))?;
-    let id = get_or_add_module_id(module_names, split.0);
-    let outputs = split.1.split(", ")
-        .map(|s| get_or_add_module_id(module_names, s))
+    let position = line.position();
+    let name = line.take_until("->")?;
+    if name.is_empty() {
+        return Err(line.error_at(position, ParseErrorKind::InvalidValue, "expected a module name"));
+    }
+    let id = get_or_add_module_id(module_names, name);
+
+    let position = line.position();
+    let outputs: Vec<String> = line.parse_separated(",")?;
+    if outputs.iter().any(String::is_empty) {
+        return Err(line.error_at(position, ParseErrorKind::InvalidValue, "expected module names"));
+    }
+    let outputs = outputs.iter()
+        .map(|output| get_or_add_module_id(module_names, output))
         .collect();
 
     Ok(Module {
@@ -224,26 +259,25 @@ struct Pulse {
     is_high: bool,
 }
 
+static TEST_INPUT_1A: &str = indoc!{
+   "broadcaster -> a, b, c
+    %a -> b
+    %b -> c
+    %c -> inv
+    &inv -> a"
+};
+
+static TEST_INPUT_1B: &str = indoc!{
+   "broadcaster -> a
+    %a -> inv, con
+    &inv -> b
+    %b -> con
+    &con -> output"
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT_1A: &str = indoc!{
-       "broadcaster -> a, b, c
-        %a -> b
-        %b -> c
-        %c -> inv
-        &inv -> a"
-    };
-
-    static TEST_INPUT_1B: &str = indoc!{
-       "broadcaster -> a
-        %a -> inv, con
-        &inv -> b
-        %b -> con
-        &con -> output"
-    };
 
     #[test]
     fn test() {
@@ -1,7 +1,6 @@
 use crate::solvers::prelude::*;
-use crate::utils::{Array2D, Point2D};
-
-static DIRECTIONS: [Point2D; 4] = [Point2D::RIGHT, Point2D::LEFT, Point2D::UP, Point2D::DOWN];
+use crate::utils::{Array2D, Bound2D, InfiniteGrid, Point2D};
+use crate::utils::polynomial::Quadratic;
 
 pub struct Day21 {
     plot_grid: Array2D<bool>,
@@ -39,6 +38,7 @@ impl FromStr for Day21 {
 
 impl Solver for Day21 {
     const INPUT_PATH: &'static str = "inputs/2023/21.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         self.simulate_steps([64])[0].into()
@@ -54,19 +54,12 @@ impl Day21 {
         assert_ne!(N, 0);
 
         let max_steps = *steps.iter().max().unwrap();
-        let extend_x = (max_steps + (self.plot_grid.size(0) / 2)) / self.plot_grid.size(0);
-        let extend_y = (max_steps + (self.plot_grid.size(1) / 2)) / self.plot_grid.size(1);
-        let grid_sizes = Point2D::new(
-            self.plot_grid.size(0) * (extend_x * 2 + 1),
-            self.plot_grid.size(1) * (extend_y * 2 + 1),
-        );
-        let start = Point2D::new(
-            self.start.x() + self.plot_grid.size(0) * extend_x,
-            self.start.y() + self.plot_grid.size(1) * extend_y,
-        );
-
-        let mut reached_grid: Array2D<bool> = Array2D::new(grid_sizes);
-        let mut reached_coords = vec![start];
+        let plots = self.plot_grid.tiled();
+
+        let reachable_bound = Bound2D::from_min_max(self.start - max_steps, self.start + max_steps);
+        // Reached plots fill most of the diamond within `max_steps`, so they are stored densely
+        let mut reached_grid = InfiniteGrid::with_capacity(reachable_bound, false);
+        let mut reached_coords = vec![self.start];
         let mut new_reached_coords = Vec::new();
         let mut prev_reachable_count = 0;
         let mut reachable_count = 0;
@@ -74,11 +67,9 @@ impl Day21 {
         let mut results = [0; N];
         for i in 0..max_steps {
             for coords in reached_coords.iter() {
-                for dir in DIRECTIONS {
-                    let coords = *coords + dir;
-                    let is_plot = self.plot_grid.get_wrap(coords);
+                for (coords, is_plot) in plots.von_neumann_neighbours(*coords) {
                     if *is_plot && !reached_grid[coords] {
-                        reached_grid[coords] = true;
+                        reached_grid.insert(coords, true);
                         new_reached_coords.push(coords);
                     }
                 }
@@ -107,45 +98,30 @@ impl Day21 {
         ];
         let y = self.simulate_steps(x);
 
-        let steps = steps as f64;
-        let x = [
-            x[0] as f64,
-            x[1] as f64,
-            x[2] as f64,
-        ];
-        let y = [
-            y[0] as f64,
-            y[1] as f64,
-            y[2] as f64,
-        ];
-
-        let a = ((x[0] * (y[2] - y[1])) + (x[1] * (y[0] - y[2])) + ( x[2]* (y[1] - y[0]))) / ((x[0] - x[1]) * (x[0] - x[2]) * (x[1] - x[2]));
-        let b = ((y[1] - y[0]) / (x[1] - x[0])) - (a * (x[0] + x[1]));
-        let c = y[0] - (a * x[0] * x[0]) - (b * x[0]);
-        
-        ((a * steps * steps) + (b * steps) + c) as i64
+        let reachable_count = Quadratic::fit_regular(x[0], self.plot_grid.width(), y).eval(steps);
+        assert!(reachable_count.is_integer(), "reachable plot count is not an integer: {reachable_count}");
+        i64::try_from(reachable_count.to_integer())
+            .unwrap_or_else(|_| panic!("reachable plot count does not fit in an i64: {reachable_count}"))
     }
 }
 
+static TEST_INPUT: &str = indoc!{
+   "...........
+    .....###.#.
+    .###.##..#.
+    ..#.#...#..
+    ....#.#....
+    .##..S####.
+    .##..#...#.
+    .......##..
+    .##.#.####.
+    .##..##.##.
+    ..........."
+};
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "...........
-        .....###.#.
-        .###.##..#.
-        ..#.#...#..
-        ....#.#....
-        .##..S####.
-        .##..#...#.
-        .......##..
-        .##.#.####.
-        .##..##.##.
-        ..........."
-    };
 
     #[test]
     fn test() {
@@ -1,10 +1,6 @@
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
-
-use itertools::Itertools;
-
 use crate::solvers::prelude::*;
 use crate::utils::{Array2D, Bound2D, Bound3D, Point2D, Point3D};
+use crate::utils::search;
 
 pub struct Day22 {
     bricks: Vec<Bound3D>,
@@ -14,15 +10,14 @@ impl FromStr for Day22 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let bricks = s.lines()
-            .map(parse_brick)
-            .try_collect()?;
+        let bricks = parse_lines(s, parse_brick)?;
         Ok(Day22 { bricks })
     }
 }
 
 impl Solver for Day22 {
     const INPUT_PATH: &'static str = "inputs/2023/22.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         let settled_bricks = self.generate_settled_bricks();
@@ -45,32 +40,26 @@ impl Solver for Day22 {
 
         let mut count = 0;
         let mut fallen_bricks = vec![usize::MAX; settled_bricks.len()];
-        let mut bricks_to_process = BinaryHeap::new();
 
         for i in 0..settled_bricks.len() {
-            fallen_bricks[i] = i;
-            for supports in settled_bricks[i].supports.iter() {
-                bricks_to_process.push(BrickToProcess {
-                    brick_id: *supports,
-                    start_height: settled_bricks[*supports].z_min,
-                });
-            }
-
-            while let Some(brick_to_process) = bricks_to_process.pop() {
-                let falls = settled_bricks[brick_to_process.brick_id].supported_by.iter()
-                    .all(|brick_id| fallen_bricks[*brick_id] == i);
+            // Every path to a brick costs its height above the removed one, so bricks are expanded
+            // bottom up and all the bricks below one have been resolved before it
+            let successors = |brick_id: &usize| {
+                let brick = &settled_bricks[*brick_id];
+                let falls = *brick_id == i || brick.supported_by.iter().all(|other_id| fallen_bricks[*other_id] == i);
+                if !falls {
+                    return Vec::new();
+                }
 
-                if falls {
+                if *brick_id != i {
                     count += 1;
-                    fallen_bricks[brick_to_process.brick_id] = i;
-                    for supports in settled_bricks[brick_to_process.brick_id].supports.iter() {
-                        bricks_to_process.push(BrickToProcess {
-                            brick_id: *supports,
-                            start_height: settled_bricks[*supports].z_min,
-                        });
-                    }
                 }
-            }
+                fallen_bricks[*brick_id] = i;
+                brick.supports.iter()
+                    .map(|other_id| (*other_id, (settled_bricks[*other_id].z_min - brick.z_min) as u64))
+                    .collect()
+            };
+            search::dijkstra(i, successors, |_| false);
         }
 
         count.into()
@@ -128,20 +117,10 @@ impl Day22 {
     }
 }
 
-fn parse_brick(line: &str) -> Result<Bound3D, ParseSolverError> {
-    let (min, max) = line.split_once('~').ok_or(parse_solver_error!("failt to split line: {line}"))?;
-    let mut split = min.split(',');
-    let min = Point3D::new(
-        split.next().ok_or(parse_solver_error!("min x not found"))?.parse()?,
-        split.next().ok_or(parse_solver_error!("min y not found"))?.parse()?,
-        split.next().ok_or(parse_solver_error!("min z not found"))?.parse()?,
-    );
-    let mut split = max.split(',');
-    let max = Point3D::new(
-        split.next().ok_or(parse_solver_error!("max x not found"))?.parse()?,
-        split.next().ok_or(parse_solver_error!("max y not found"))?.parse()?,
-        split.next().ok_or(parse_solver_error!("max z not found"))?.parse()?,
-    );
+fn parse_brick(line: &mut LineCursor) -> Result<Bound3D, ParseSolverError> {
+    let min = Point3D::new(line.parse_until(",")?, line.parse_until(",")?, line.parse_until("~")?);
+    let max = Point3D::new(line.parse_until(",")?, line.parse_until(",")?, line.parse_next()?);
+    line.finish()?;
     Ok(Bound3D::from_min_max(min, max))
 }
 
@@ -159,38 +138,19 @@ struct SettledBrick {
     z_max :i64,
 }
 
-#[derive(Eq, PartialEq)]
-struct BrickToProcess {
-    brick_id: usize,
-    start_height: i64,
-}
-
-impl PartialOrd for BrickToProcess {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for BrickToProcess {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.start_height.cmp(&other.start_height)
-    }
-}
+static TEST_INPUT: &str = indoc!{
+   "1,0,1~1,2,1
+    0,0,2~2,0,2
+    0,2,3~2,2,3
+    0,0,4~0,2,4
+    2,0,5~2,2,5
+    0,1,6~2,1,6
+    1,1,8~1,1,9"
+};
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "1,0,1~1,2,1
-        0,0,2~2,0,2
-        0,2,3~2,2,3
-        0,0,4~0,2,4
-        2,0,5~2,2,5
-        0,1,6~2,1,6
-        1,1,8~1,1,9"
-    };
 
     #[test]
     fn test() {
@@ -1,7 +1,6 @@
 use crate::solvers::prelude::*;
 use crate::utils::{Array2D, Point2D};
-
-static DIRECTIONS: [Point2D; 4] = [Point2D::RIGHT, Point2D::UP, Point2D::LEFT, Point2D::DOWN];
+use crate::utils::graph::GridGraph;
 
 pub struct Day23 {
     grid: Array2D<Tile>,
@@ -17,10 +16,10 @@ impl FromStr for Day23 {
             match c {
                 '.' => Ok(Tile::Path),
                 '#' => Ok(Tile::Forest),
-                '^' => Ok(Tile::Slope(3)),
-                '>' => Ok(Tile::Slope(0)),
-                'v' => Ok(Tile::Slope(1)),
-                '<' => Ok(Tile::Slope(2)),
+                '^' => Ok(Tile::Slope(Point2D::DOWN)),
+                '>' => Ok(Tile::Slope(Point2D::RIGHT)),
+                'v' => Ok(Tile::Slope(Point2D::UP)),
+                '<' => Ok(Tile::Slope(Point2D::LEFT)),
                 _ => Err(parse_solver_error!("invalid char: {c}")),
             }
         })?;
@@ -54,6 +53,7 @@ impl FromStr for Day23 {
 
 impl Solver for Day23 {
     const INPUT_PATH: &'static str = "inputs/2023/23.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         self.solve(false).into()
@@ -65,35 +65,19 @@ impl Solver for Day23 {
 }
 
 impl Day23 {
-    fn solve(&self, part2: bool) -> usize {
-        let mut path_grid = Array2D::new(self.grid.sizes());
-        path_grid[self.start] = true;
-        let mut steps = Vec::new();
-        steps.push(Step::new(self.start));
-        let mut longest_path = 0;
-
-        while let Some (step) = steps.last_mut() {
-            if step.dir >= DIRECTIONS.len() {
-                path_grid[step.pos] = false;
-                steps.pop();
-                continue;
-            }
-
-            let dir = step.dir;
-            step.dir += 1;
-
-            let next_pos = step.pos + DIRECTIONS[dir];
-            if next_pos == self.end {
-                longest_path = usize::max(steps.len(), longest_path);
-            } else if let Some(tile) = self.grid.try_get(next_pos) {
-                if !path_grid[next_pos] && tile.can_step(dir, part2) {
-                    path_grid[next_pos] = true;
-                    steps.push(Step::new(next_pos));
-                }
-            }
-        }
-
-        longest_path
+    fn solve(&self, part2: bool) -> u64 {
+        let grid_graph = GridGraph::new(
+            &self.grid,
+            |tile| *tile != Tile::Forest,
+            |from, to| self.grid[to].can_step(to - from, part2),
+            &[self.start, self.end],
+        );
+        let start = grid_graph.node_id(self.start).unwrap();
+        let end = grid_graph.node_id(self.end).unwrap();
+
+        grid_graph.graph()
+            .longest_path_parallel(start, end, |length| *length)
+            .map_or(0, |path| path.length)
     }
 }
 
@@ -101,11 +85,11 @@ impl Day23 {
 enum Tile {
     Path,
     Forest,
-    Slope(usize),
+    Slope(Point2D),
 }
 
 impl Tile {
-    fn can_step(&self, dir: usize, part2: bool) -> bool {
+    fn can_step(&self, dir: Point2D, part2: bool) -> bool {
         match self {
             Tile::Path => true,
             Tile::Forest => false,
@@ -120,50 +104,35 @@ impl Tile {
     }
 }
 
-struct Step {
-    pos: Point2D,
-    dir: usize,
-}
-
-impl Step {
-    fn new(pos: Point2D) -> Step {
-        Step {
-            pos,
-            dir: 0,
-        }
-    }
-}
+static TEST_INPUT: &str = indoc!{
+   "#.#####################
+    #.......#########...###
+    #######.#########.#.###
+    ###.....#.>.>.###.#.###
+    ###v#####.#v#.###.#.###
+    ###.>...#.#.#.....#...#
+    ###v###.#.#.#########.#
+    ###...#.#.#.......#...#
+    #####.#.#.#######.#.###
+    #.....#.#.#.......#...#
+    #.#####.#.#.#########v#
+    #.#...#...#...###...>.#
+    #.#.#v#######v###.###v#
+    #...#.>.#...>.>.#.###.#
+    #####v#.#.###v#.#.###.#
+    #.....#...#...#.#.#...#
+    #.#########.###.#.#.###
+    #...###...#...#...#.###
+    ###.###.#.###v#####v###
+    #...#...#.#.>.>.#.>.###
+    #.###.###.#.###.#.#v###
+    #.....###...###...#...#
+    #####################.#"
+};
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "#.#####################
-        #.......#########...###
-        #######.#########.#.###
-        ###.....#.>.>.###.#.###
-        ###v#####.#v#.###.#.###
-        ###.>...#.#.#.....#...#
-        ###v###.#.#.#########.#
-        ###...#.#.#.......#...#
-        #####.#.#.#######.#.###
-        #.....#.#.#.......#...#
-        #.#####.#.#.#########v#
-        #.#...#...#...###...>.#
-        #.#.#v#######v###.###v#
-        #...#.>.#...>.>.#.###.#
-        #####v#.#.###v#.#.###.#
-        #.....#...#...#.#.#...#
-        #.#########.###.#.#.###
-        #...###...#...#...#.###
-        ###.###.#.###v#####v###
-        #...#...#.#.>.>.#.>.###
-        #.###.###.#.###.#.#v###
-        #.....###...###...#...#
-        #####################.#"
-    };
 
     #[test]
     fn test() {
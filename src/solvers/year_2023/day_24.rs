@@ -2,8 +2,6 @@ use std::ops::RangeInclusive;
 
 use crate::solvers::prelude::*;
 
-use itertools::Itertools;
-
 pub struct Day24 {
     hailstones: Vec<Hailstone>
 }
@@ -12,15 +10,14 @@ impl FromStr for Day24 {
     type Err = ParseSolverError;
 
     fn from_str(s: &str) -> Result<Self, ParseSolverError> {
-        let hailstones = s.lines()
-            .map(Hailstone::from_str)
-            .try_collect()?;
+        let hailstones = parse_lines(s, Hailstone::parse)?;
         Ok(Day24 { hailstones })
     }
 }
 
 impl Solver for Day24 {
     const INPUT_PATH: &'static str = "inputs/2023/24.txt";
+    const EXAMPLE_INPUTS: &'static [&'static str] = &[TEST_INPUT];
 
     fn run_part1(&self) -> SolverResult {
         const MIN: f64 = 200_000_000_000_000.0;
@@ -160,38 +157,26 @@ struct Hailstone {
     vel: (f64, f64, f64),
 }
 
-impl FromStr for Hailstone {
-    type Err = ParseSolverError;
-
-    fn from_str(line: &str) -> Result<Self, ParseSolverError> {
-        let (position, velocity) = line.split_once(" @ ").ok_or(parse_solver_error!("fail to split line: {line}"))?;
-        let pos = parse_point(position)?;
-        let vel = parse_point(velocity)?;
+impl Hailstone {
+    fn parse(line: &mut LineCursor) -> Result<Hailstone, ParseSolverError> {
+        let pos = (line.parse_until(",")?, line.parse_until(",")?, line.parse_until("@")?);
+        let vel = (line.parse_until(",")?, line.parse_until(",")?, line.parse_next()?);
+        line.finish()?;
         Ok(Hailstone { pos, vel })
     }
 }
 
-fn parse_point(s: &str) -> Result<(f64, f64, f64), ParseSolverError> {
-    let mut split = s.split(", ");
-    Ok((
-        split.next().ok_or(parse_solver_error!("x not found for str: {s}"))?.parse()?,
-        split.next().ok_or(parse_solver_error!("y not found for str: {s}"))?.parse()?,
-        split.next().ok_or(parse_solver_error!("z not found for str: {s}"))?.parse()?,
-    ))
-}
+static TEST_INPUT: &str = indoc!{
+   "19, 13, 30 @ -2, 1, -2
+    18, 19, 22 @ -1, -1, -2
+    20, 25, 34 @ -2, -2, -4
+    12, 31, 28 @ -1, -2, -1
+    20, 19, 15 @ 1, -5, -3"
+};
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    static TEST_INPUT: &str = indoc!{
-       "19, 13, 30 @ -2, 1, -2
-        18, 19, 22 @ -1, -1, -2
-        20, 25, 34 @ -2, -2, -4
-        12, 31, 28 @ -1, -2, -1
-        20, 19, 15 @ 1, -5, -3"
-    };
 
     #[test]
     fn test() {
@@ -1,8 +1,17 @@
 mod array_md;
 mod bound_md;
+pub mod cycle;
+mod direction;
+mod infinite_grid;
+mod interval;
 pub mod graph;
 mod point;
+pub mod polynomial;
+mod rotation;
+pub mod search;
 mod slice_md;
+mod sparse_grid;
+mod tiled_md;
 
 pub use array_md::ArrayMD;
 pub use array_md::Array2D;
@@ -11,9 +20,18 @@ pub use bound_md::BoundMD;
 pub use bound_md::Range;
 pub use bound_md::Bound2D;
 pub use bound_md::Bound3D;
+pub use bound_md::BoundSet;
 pub use bound_md::OverlapResult;
+pub use direction::Direction2D;
+pub use infinite_grid::InfiniteGrid;
+pub use interval::IntervalSet;
+pub use interval::RangeMap;
 pub use point::Point;
 pub use point::Point2D;
 pub use point::Point3D;
+pub use rotation::Rotation3D;
 pub use slice_md::SliceMD;
-pub use slice_md::SliceMutMD;
\ No newline at end of file
+pub use slice_md::SliceMutMD;
+pub use sparse_grid::SparseGrid;
+#[allow(unused_imports)]
+pub use tiled_md::TiledMD;
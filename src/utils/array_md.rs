@@ -40,19 +40,24 @@ impl<const D: usize, T> ArrayMD<D, T> {
     }
 
     pub fn get_wrap(&self, coords: impl Into<Point<D>>) -> &T {
-        let mut coords = coords.into();
-        coords %= self.sizes;
-        let index = self.get_index(coords);
+        let index = self.get_index(self.wrap_coords(coords));
         &self.data[index]
     }
 
     pub fn get_wrap_mut(&mut self, coords: impl Into<Point<D>>) -> &mut T {
-        let mut coords = coords.into();
-        coords %= self.sizes;
-        let index = self.get_index(coords);
+        let index = self.get_index(self.wrap_coords(coords));
         &mut self.data[index]
     }
 
+    // Coords brought back in bound as if the array was repeated infinitely
+    pub fn wrap_coords(&self, coords: impl Into<Point<D>>) -> Point<D> {
+        let mut coords = coords.into();
+        for d in 0..D {
+            coords[d] = coords[d].rem_euclid(self.sizes[d]);
+        }
+        coords
+    }
+
     pub fn try_get(&self, coords: impl Into<Point<D>>) -> Option<&T> {
         let index = self.try_get_index(coords.into())?;
         Some(&self.data[index])
@@ -106,6 +111,44 @@ impl<const D: usize, T> ArrayMD<D, T> {
     }
 }
 
+#[allow(dead_code)]
+impl<const D: usize, T> ArrayMD<D, T> {
+    // In bound neighbours sharing a face, with their values
+    pub fn von_neumann_neighbours(&self, coords: impl Into<Point<D>>) -> impl Iterator<Item = (Point<D>, &T)> {
+        coords.into()
+            .von_neumann_neighbours()
+            .filter(|neighbour| self.is_in_bound(*neighbour))
+            .map(|neighbour| (neighbour, &self[neighbour]))
+    }
+
+    // In bound neighbours sharing a face, an edge or a corner, with their values
+    pub fn moore_neighbours(&self, coords: impl Into<Point<D>>) -> impl Iterator<Item = (Point<D>, &T)> {
+        coords.into()
+            .moore_neighbours()
+            .filter(|neighbour| self.is_in_bound(*neighbour))
+            .map(|neighbour| (neighbour, &self[neighbour]))
+    }
+
+    // Same as `von_neumann_neighbours`, with wrapped coords
+    pub fn von_neumann_neighbours_wrap(&self, coords: impl Into<Point<D>>) -> impl Iterator<Item = (Point<D>, &T)> {
+        coords.into()
+            .von_neumann_neighbours()
+            .map(|neighbour| {
+                let neighbour = self.wrap_coords(neighbour);
+                (neighbour, &self[neighbour])
+            })
+    }
+
+    // Same as `moore_neighbours`, with wrapped coords
+    pub fn moore_neighbours_wrap(&self, coords: impl Into<Point<D>>) -> impl Iterator<Item = (Point<D>, &T)> {
+        coords.into()
+            .moore_neighbours()
+            .map(|neighbour| {
+                let neighbour = self.wrap_coords(neighbour);
+                (neighbour, &self[neighbour])
+            })
+    }
+}
 
 impl<const D: usize, I: Into<Point<D>>, T> Index<I> for ArrayMD<D, T> {
     type Output = T;
@@ -134,9 +177,17 @@ impl<const D: usize, T: Default + Clone> ArrayMD<D, T> {
 }
 
 impl<const D: usize, T: Clone> ArrayMD<D, T> {
+    pub fn filled(sizes: impl Into<Point<D>>, value: T) -> Self {
+        let sizes: Point<D> = sizes.into();
+        ArrayMD {
+            data: vec![value; sizes.volume() as usize],
+            sizes,
+        }
+    }
+
     pub fn copy_to_with_offset(&self, dst: &mut ArrayMD<D, T>, offset: impl Into<Point<D>>) {
         let offset: Point<D> = offset.into();
-        if !dst.is_in_bound(self.sizes + offset) {
+        if !dst.is_in_bound(offset) || !dst.is_in_bound(self.sizes + offset - 1) {
             panic!("copy_to_with_offset out of bound (self.sizes = {:?}, dst.sizes = {:?}, offset = {:?})", self.sizes, dst.sizes, offset);
         }
 
@@ -186,10 +237,11 @@ impl<T> Array2D<T> {
             return Err(ParseSolverError::new(format!("invalid array width (= {width}) or height (= {height})")));
         }
 
+        let lines = s.lines().enumerate();
         let data = if inverse_y {
-            parse_str_internal(s.lines().rev(), width, height, f)?
+            parse_str_internal(lines.collect::<Vec<_>>().into_iter().rev(), width, height, f)?
         } else {
-            parse_str_internal(s.lines(), width, height, f)?
+            parse_str_internal(lines, width, height, f)?
         };
 
         return Ok(Array2D {
@@ -197,13 +249,13 @@ impl<T> Array2D<T> {
             sizes: Point2D::new(width as i64, height as i64),
         });
 
-        fn parse_str_internal<'a, T>(lines: impl Iterator<Item = &'a str>, width: usize, height: usize, mut f: impl FnMut(Point2D, char) -> Result<T, ParseSolverError>) -> Result<Vec<T>, ParseSolverError> {
+        fn parse_str_internal<'a, T>(lines: impl Iterator<Item = (usize, &'a str)>, width: usize, height: usize, mut f: impl FnMut(Point2D, char) -> Result<T, ParseSolverError>) -> Result<Vec<T>, ParseSolverError> {
             let size = width * height;
             let mut data = Vec::with_capacity(size);
-            for (y, line) in lines.enumerate() {
+            for (y, (line_index, line)) in lines.enumerate() {
                 for (x, c) in line.chars().enumerate() {
                     let coords = Point2D::new(x as i64, y as i64);
-                    data.push(f(coords, c)?);
+                    data.push(f(coords, c).map_err(|err| err.at(line_index + 1, Some(x + 1), line))?);
                 }
             }
             if data.len() != size {
@@ -253,4 +305,23 @@ impl<T> Array3D<T> {
     pub fn depth(&self) -> i64 {
         self.sizes[2]
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_array_neighbours() {
+        let array = Array2D::from_str("abc\ndef", false).unwrap();
+        let values: Vec<char> = array.von_neumann_neighbours([0, 0]).map(|(_, c)| *c).collect();
+        assert_eq!(values, vec!['b', 'd']);
+        let values: Vec<char> = array.moore_neighbours([1, 0]).map(|(_, c)| *c).collect();
+        assert_eq!(values, vec!['a', 'c', 'd', 'e', 'f']);
+
+        let neighbours: Vec<(Point2D, char)> = array.von_neumann_neighbours_wrap([0, 0]).map(|(coords, c)| (coords, *c)).collect();
+        assert_eq!(neighbours, vec![(Point2D::new(2, 0), 'c'), (Point2D::new(1, 0), 'b'), (Point2D::new(0, 1), 'd'), (Point2D::new(0, 1), 'd')]);
+        assert_eq!(array.moore_neighbours_wrap([0, 0]).count(), 8);
+        assert_eq!(*array.get_wrap([-1, -3]), 'f');
+    }
+}
@@ -141,27 +141,23 @@ impl<const D: usize> BoundMD<D> {
     }
 
     pub fn overlap(&self, other: &Self) -> OverlapResult {
-        // TODO : This can probably be optimized
-
-        if self.start == other.start && self.end() == other.end() {
-            return OverlapResult::Equals;
-        }
-
-        if self.contains(other.start) && self.contains(other.end() - 1) {
-            return OverlapResult::SelfContainsOther;
-        }
-
-        if other.contains(self.start) && other.contains(self.end() - 1) {
-            return OverlapResult::OtherContainsSelf;
-        }
-
+        let (self_end, other_end) = (self.end(), other.end());
+        let mut self_contains_other = true;
+        let mut other_contains_self = true;
         for d in 0..D {
-            if self.start[d] >= other.end()[d] || other.start[d] >= self.end()[d] {
+            if self.start[d] >= other_end[d] || other.start[d] >= self_end[d] {
                 return OverlapResult::None;
             }
+            self_contains_other &= self.start[d] <= other.start[d] && other_end[d] <= self_end[d];
+            other_contains_self &= other.start[d] <= self.start[d] && self_end[d] <= other_end[d];
         }
 
-        OverlapResult::Intersect
+        match (self_contains_other, other_contains_self) {
+            (true, true) => OverlapResult::Equals,
+            (true, false) => OverlapResult::SelfContainsOther,
+            (false, true) => OverlapResult::OtherContainsSelf,
+            (false, false) => OverlapResult::Intersect,
+        }
     }
 
     pub fn and(&self, other: &Self) -> Option<Self> {
@@ -175,9 +171,75 @@ impl<const D: usize> BoundMD<D> {
         Some(Self::from_min_max(min, max))
     }
 
+    // Disjoint bounds covering the coords of self that are not in other, at most 2 * D of them
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(intersection) = self.and(other) else {
+            return vec![*self];
+        };
+
+        // Slice off the parts before and after the intersection, one dimension at a time
+        let mut pieces = Vec::new();
+        let mut remaining = *self;
+        for d in 0..D {
+            if remaining.start[d] < intersection.start[d] {
+                let mut piece = remaining;
+                piece.set_end_d(d, intersection.start[d]);
+                pieces.push(piece);
+                remaining.set_min_d(d, intersection.start[d]);
+            }
+            if intersection.end()[d] < remaining.end()[d] {
+                let mut piece = remaining;
+                piece.set_min_d(d, intersection.end()[d]);
+                pieces.push(piece);
+                remaining.set_end_d(d, intersection.end()[d]);
+            }
+        }
+        pieces
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sizes.min_coord() == 0
+    }
+
+    // Splits along dimension `d`, coords lower than `at` go to the first bound. Empty parts are None.
+    pub fn split_d(&self, d: usize, at: i64) -> (Option<Self>, Option<Self>) {
+        if at <= self.start[d] {
+            return (None, Some(*self));
+        }
+        if at >= self.end()[d] {
+            return (Some(*self), None);
+        }
+
+        let mut lower = *self;
+        let mut upper = *self;
+        lower.set_end_d(d, at);
+        upper.set_min_d(d, at);
+        (Some(lower), Some(upper))
+    }
+
     pub fn iter_d(&self, d: usize) -> std::ops::Range<i64> {
         self.start[d]..self.end()[d]
     }
+
+    // Every coords inside the bound, dimension 0 first
+    pub fn iter_points(&self) -> impl Iterator<Item = Point<D>> {
+        let (start, end) = (self.start, self.end());
+        let mut next = (!self.is_empty()).then_some(start);
+        std::iter::from_fn(move || {
+            let coords = next?;
+            let mut following = coords;
+            next = None;
+            for d in 0..D {
+                following[d] += 1;
+                if following[d] < end[d] {
+                    next = Some(following);
+                    break;
+                }
+                following[d] = start[d];
+            }
+            Some(coords)
+        })
+    }
 }
 
 #[derive(Eq, PartialEq)]
@@ -189,6 +251,88 @@ pub enum OverlapResult {
     Equals,
 }
 
+// Union of disjoint bounds
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BoundSet<const D: usize> {
+    bounds: Vec<BoundMD<D>>,
+}
+
+#[allow(dead_code)]
+impl<const D: usize> BoundSet<D> {
+    pub fn new() -> Self {
+        BoundSet { bounds: Vec::new() }
+    }
+
+    pub fn bounds(&self) -> &[BoundMD<D>] {
+        &self.bounds
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    pub fn volume(&self) -> i64 {
+        self.bounds.iter().map(|bound| bound.volume()).sum()
+    }
+
+    pub fn contains(&self, coords: impl Into<Point<D>>) -> bool {
+        let coords = coords.into();
+        self.bounds.iter().any(|bound| bound.contains(coords))
+    }
+
+    pub fn insert(&mut self, bound: BoundMD<D>) {
+        self.remove(bound);
+        if !bound.is_empty() {
+            self.bounds.push(bound);
+        }
+    }
+
+    // Only the bounds overlapping `bound` are split, the others stay in place
+    pub fn remove(&mut self, bound: BoundMD<D>) {
+        let mut pieces = Vec::new();
+        self.bounds.retain(|existing_bound| {
+            if existing_bound.and(&bound).is_none() {
+                return true;
+            }
+            pieces.extend(existing_bound.subtract(&bound));
+            false
+        });
+        self.bounds.extend(pieces);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for bound in other.bounds.iter() {
+            union.insert(*bound);
+        }
+        union
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for bound in other.bounds.iter() {
+            difference.remove(*bound);
+        }
+        difference
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let bounds = self.bounds
+            .iter()
+            .flat_map(|bound| other.bounds.iter().filter_map(|other_bound| bound.and(other_bound)))
+            .collect();
+        BoundSet { bounds }
+    }
+}
+
+impl<const D: usize> From<BoundMD<D>> for BoundSet<D> {
+    fn from(bound: BoundMD<D>) -> Self {
+        let mut set = BoundSet::new();
+        set.insert(bound);
+        set
+    }
+}
+
 pub type Range = BoundMD<1>;
 
 impl Range {
@@ -224,3 +368,64 @@ impl Bound3D {
         Bound2D::new(self.start().yz(), self.sizes().yz())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subtract() {
+        let bound = Bound2D::from_min_max([0, 0], [9, 9]);
+        let hole = Bound2D::from_min_max([3, 3], [5, 5]);
+        let pieces = bound.subtract(&hole);
+        assert_eq!(pieces.len(), 4);
+        assert_eq!(pieces.iter().map(|piece| piece.volume()).sum::<i64>(), 100 - 9);
+        assert!(pieces.iter().all(|piece| piece.and(&hole).is_none()));
+
+        assert_eq!(bound.subtract(&Bound2D::from_min_max([20, 0], [25, 5])), vec![bound]);
+        assert!(hole.subtract(&bound).is_empty());
+        assert_eq!(bound.subtract(&Bound2D::from_min_max([5, -5], [15, 15])), vec![Bound2D::from_min_max([0, 0], [4, 9])]);
+    }
+
+    #[test]
+    fn test_split() {
+        let bound = Bound2D::from_min_max([0, 0], [9, 9]);
+        assert_eq!(bound.split_d(1, 4), (Some(Bound2D::from_min_max([0, 0], [9, 3])), Some(Bound2D::from_min_max([0, 4], [9, 9]))));
+        assert_eq!(bound.split_d(0, 0), (None, Some(bound)));
+        assert_eq!(bound.split_d(0, 10), (Some(bound), None));
+    }
+
+    #[test]
+    fn test_iter_points() {
+        let points: Vec<Point<2>> = Bound2D::from_min_max([-1, 5], [0, 6]).iter_points().collect();
+        assert_eq!(points, vec![Point::from([-1, 5]), Point::from([0, 5]), Point::from([-1, 6]), Point::from([0, 6])]);
+        assert_eq!(Bound2D::new([0, 0], [3, 0]).iter_points().count(), 0);
+    }
+
+    #[test]
+    fn test_overlap() {
+        let bound = Bound2D::from_min_max([0, 0], [9, 9]);
+        assert!(bound.overlap(&bound) == OverlapResult::Equals);
+        assert!(bound.overlap(&Bound2D::from_min_max([1, 1], [2, 2])) == OverlapResult::SelfContainsOther);
+        assert!(bound.overlap(&Bound2D::from_min_max([-1, 0], [9, 9])) == OverlapResult::OtherContainsSelf);
+        assert!(bound.overlap(&Bound2D::from_min_max([5, 5], [15, 15])) == OverlapResult::Intersect);
+        assert!(bound.overlap(&Bound2D::from_min_max([10, 0], [15, 15])) == OverlapResult::None);
+    }
+
+    #[test]
+    fn test_bound_set() {
+        let mut set = BoundSet::from(Bound3D::from_min_max([0, 0, 0], [2, 2, 2]));
+        set.insert(Bound3D::from_min_max([1, 1, 1], [3, 3, 3]));
+        assert_eq!(set.volume(), 27 + 27 - 8);
+        set.remove(Bound3D::from_min_max([1, 1, 1], [1, 1, 1]));
+        assert_eq!(set.volume(), 45);
+        assert!(!set.contains([1, 1, 1]));
+        assert!(set.contains([3, 3, 3]));
+
+        let other = BoundSet::from(Bound3D::from_min_max([2, 2, 2], [4, 4, 4]));
+        assert_eq!(set.intersection(&other).volume(), 8);
+        assert_eq!(set.union(&other).volume(), 45 + 27 - 8);
+        assert_eq!(set.difference(&other).volume(), 45 - 8);
+        assert!(set.difference(&set).is_empty());
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// The sequence start, step(start), step(step(start))... repeats itself: state `prefix + period` is equal to state `prefix`.
+// The step function must be deterministic over a finite set of states, otherwise the detection may never end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    // Number of states before entering the cycle
+    pub prefix: usize,
+    pub period: usize,
+}
+
+#[allow(dead_code)]
+impl Cycle {
+    // Smallest step index whose state is equal to the state after `n` steps
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+
+    pub fn state_after<S: Clone>(&self, start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        let mut state = start.clone();
+        for _ in 0..self.reduce(n) {
+            state = step(&state);
+        }
+        state
+    }
+}
+
+// Floyd's tortoise and hare, constant memory
+#[allow(dead_code)]
+pub fn floyd<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+// Brent's algorithm, constant memory and usually fewer steps than Floyd's
+#[allow(dead_code)]
+pub fn brent<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    tortoise = start.clone();
+    hare = start.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+// Remembers every state, each step is only computed once. Returns the cycle with the states from
+// `start` to the last state before the cycle repeats, so `states[cycle.reduce(n)]` is the state after `n` steps.
+pub fn find_cycle<S: Clone + Eq + Hash>(start: S, mut step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    loop {
+        let state = step(states.last().unwrap());
+        if let Some(&prefix) = indices.get(&state) {
+            let period = states.len() - prefix;
+            return (Cycle { prefix, period }, states);
+        }
+        indices.insert(state.clone(), states.len());
+        states.push(state);
+    }
+}
+
+// State after `n` steps, only simulated until the sequence repeats itself
+pub fn state_after<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (cycle, mut states) = find_cycle(start, step);
+    states.swap_remove(cycle.reduce(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, then 3 to 9 repeating
+    fn step(n: &u32) -> u32 {
+        if *n == 9 { 3 } else { n + 1 }
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        let expected = Cycle { prefix: 3, period: 7 };
+        assert_eq!(floyd(&0, step), expected);
+        assert_eq!(brent(&0, step), expected);
+
+        let (cycle, states) = find_cycle(0, step);
+        assert_eq!(cycle, expected);
+        assert_eq!(states, (0..10).collect::<Vec<u32>>());
+
+        assert_eq!(expected.reduce(2), 2);
+        assert_eq!(expected.reduce(10), 3);
+        assert_eq!(expected.state_after(&0, step, 1_000_000_000), 3 + (1_000_000_000 - 3) % 7);
+        assert_eq!(state_after(0, step, 1_000_000_000), 3 + (1_000_000_000 - 3) % 7);
+        assert_eq!(state_after(0, step, 5), 5);
+
+        // Cycle starting right away
+        assert_eq!(brent(&3, step), Cycle { prefix: 0, period: 7 });
+        assert_eq!(floyd(&3, step), Cycle { prefix: 0, period: 7 });
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::solvers::{ParseErrorKind, ParseSolverError};
+use crate::utils::Point2D;
+
+// Compass directions, North is `Point2D::UP` and East is `Point2D::RIGHT`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction2D {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+#[allow(dead_code)]
+impl Direction2D {
+    // Clockwise from North
+    pub const ALL: [Direction2D; 8] = [
+        Direction2D::North,
+        Direction2D::NorthEast,
+        Direction2D::East,
+        Direction2D::SouthEast,
+        Direction2D::South,
+        Direction2D::SouthWest,
+        Direction2D::West,
+        Direction2D::NorthWest,
+    ];
+    pub const ORTHOGONAL: [Direction2D; 4] = [Direction2D::North, Direction2D::East, Direction2D::South, Direction2D::West];
+    pub const DIAGONAL: [Direction2D; 4] = [Direction2D::NorthEast, Direction2D::SouthEast, Direction2D::SouthWest, Direction2D::NorthWest];
+
+    // Accepts arrows `^>v<`, `UDLR` and `NESW`
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'U' | 'N' => Some(Direction2D::North),
+            '>' | 'R' | 'E' => Some(Direction2D::East),
+            'v' | 'D' | 'S' => Some(Direction2D::South),
+            '<' | 'L' | 'W' => Some(Direction2D::West),
+            _ => None,
+        }
+    }
+
+    // Unit vector for orthogonal directions, (±1, ±1) for diagonals
+    pub fn from_point(point: Point2D) -> Option<Self> {
+        Direction2D::ALL.into_iter().find(|dir| dir.to_point() == point)
+    }
+
+    pub fn to_point(self) -> Point2D {
+        match self {
+            Direction2D::North => Point2D::UP,
+            Direction2D::NorthEast => Point2D::UP + Point2D::RIGHT,
+            Direction2D::East => Point2D::RIGHT,
+            Direction2D::SouthEast => Point2D::DOWN + Point2D::RIGHT,
+            Direction2D::South => Point2D::DOWN,
+            Direction2D::SouthWest => Point2D::DOWN + Point2D::LEFT,
+            Direction2D::West => Point2D::LEFT,
+            Direction2D::NorthWest => Point2D::UP + Point2D::LEFT,
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction2D::East | Direction2D::West)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction2D::North | Direction2D::South)
+    }
+
+    // 90° clockwise
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    // 90° counterclockwise
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    // Rotates clockwise by steps of 45°
+    pub fn rotate(self, steps: i32) -> Self {
+        Direction2D::ALL[(self.index() as i32 + steps).rem_euclid(8) as usize]
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Direction2D {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Direction2D::North => "N",
+            Direction2D::NorthEast => "NE",
+            Direction2D::East => "E",
+            Direction2D::SouthEast => "SE",
+            Direction2D::South => "S",
+            Direction2D::SouthWest => "SW",
+            Direction2D::West => "W",
+            Direction2D::NorthWest => "NW",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for Direction2D {
+    type Err = ParseSolverError;
+
+    fn from_str(s: &str) -> Result<Self, ParseSolverError> {
+        let mut chars = s.chars();
+        let direction = match (chars.next(), chars.next(), chars.next()) {
+            (Some(c), None, _) => Direction2D::from_char(c),
+            (Some(_), Some(_), None) => Direction2D::ALL.into_iter().find(|dir| dir.to_string() == s),
+            _ => None,
+        };
+        direction.ok_or_else(|| ParseSolverError::with_kind(ParseErrorKind::InvalidValue, format!("invalid direction: {s}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction2D::North.turn_right(), Direction2D::East);
+        assert_eq!(Direction2D::North.turn_left(), Direction2D::West);
+        assert_eq!(Direction2D::SouthWest.reverse(), Direction2D::NorthEast);
+        assert_eq!(Direction2D::West.rotate(-1), Direction2D::SouthWest);
+        assert_eq!(Direction2D::East.to_point(), Point2D::RIGHT);
+        assert_eq!(Direction2D::from_point(Point2D::new(-1, 1)), Some(Direction2D::NorthWest));
+        assert_eq!(Direction2D::from_point(Point2D::new(2, 0)), None);
+        assert!(Direction2D::DIAGONAL.iter().all(|dir| dir.is_diagonal()));
+    }
+
+    #[test]
+    fn test_parse_direction() {
+        assert_eq!(Direction2D::from_char('v'), Some(Direction2D::South));
+        assert_eq!(Direction2D::from_char('L'), Some(Direction2D::West));
+        assert_eq!("E".parse::<Direction2D>().unwrap(), Direction2D::East);
+        assert_eq!("SE".parse::<Direction2D>().unwrap(), Direction2D::SouthEast);
+        assert!("X".parse::<Direction2D>().is_err());
+        assert!("NNE".parse::<Direction2D>().is_err());
+    }
+}
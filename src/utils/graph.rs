@@ -1,4 +1,20 @@
-use itertools::Itertools;
+mod components;
+mod cut;
+mod export;
+mod grid;
+mod labeled;
+mod longest;
+mod path;
+
+#[allow(unused_imports)]
+pub use cut::MinCut;
+pub use export::{GraphFormat, GraphStyle};
+pub use grid::GridGraph;
+pub use labeled::{AdjacencyFormat, LabeledGraph};
+#[allow(unused_imports)]
+pub use longest::LongestPath;
+#[allow(unused_imports)]
+pub use path::ShortestPath;
 
 #[derive(Clone)]
 pub struct Graph<N, E> {
@@ -21,6 +37,7 @@ impl<N, E> Graph<N, E> {
             id,
             value,
             connections: Vec::new(),
+            has_been_removed: false,
         });
         id
     }
@@ -66,8 +83,123 @@ impl<N, E> Graph<N, E> {
             let index = to_node.connections.iter().position(|connection| connection.edge_id == edge_id).unwrap();
             to_node.connections.remove(index);
         }
+    }
+
+    // Removes the node edges, the node itself stays as a tombstone until `compact` is called
+    pub fn remove_node(&mut self, node_id: usize) {
+        for edge_id in self.node_edge_ids(node_id) {
+            self.remove_edge(edge_id);
+        }
+        self.nodes[node_id].has_been_removed = true;
+    }
+
+    // Moves the edges of `removed` to `kept`, then removes `removed`. Edges between both nodes are dropped,
+    // parallel edges are kept (as needed by Karger's algorithm).
+    pub fn contract_nodes(&mut self, kept: usize, removed: usize) {
+        self.contract_nodes_internal(kept, removed, None::<fn(&mut E, &E)>);
+    }
+
+    // Same as `contract_nodes`, but an edge parallel to an existing one is merged into it with `combine`
+    pub fn contract_nodes_with(&mut self, kept: usize, removed: usize, combine: impl FnMut(&mut E, &E)) {
+        self.contract_nodes_internal(kept, removed, Some(combine));
+    }
 
+    fn contract_nodes_internal(&mut self, kept: usize, removed: usize, mut combine: Option<impl FnMut(&mut E, &E)>) {
+        assert_ne!(kept, removed, "cannot contract a node with itself");
+
+        for edge_id in self.node_edge_ids(removed) {
+            self.remove_edge(edge_id);
+
+            let edge = &mut self.edges[edge_id];
+            if edge.from == kept || edge.to == kept {
+                continue;
+            }
+            if edge.from == removed {
+                edge.from = kept;
+            }
+            if edge.to == removed {
+                edge.to = kept;
+            }
+
+            let (from, to, is_bidirectional) = (edge.from, edge.to, edge.is_bidirectional);
+            if let Some(combine) = &mut combine {
+                if let Some(parallel_edge_id) = self.find_parallel_edge(from, to, is_bidirectional) {
+                    let (edge, parallel_edge) = get_two_mut(&mut self.edges, edge_id, parallel_edge_id);
+                    combine(&mut parallel_edge.value, &edge.value);
+                    continue;
+                }
+            }
+            self.restore_edge(edge_id);
+        }
 
+        self.nodes[removed].has_been_removed = true;
+    }
+
+    // Drops removed nodes and edges, then renumbers the remaining ones keeping their order
+    pub fn compact(&mut self) -> CompactMapping {
+        let mut mapping = CompactMapping {
+            nodes: vec![None; self.nodes.len()],
+            edges: vec![None; self.edges.len()],
+        };
+
+        for (new_id, node) in self.nodes.iter().filter(|node| !node.has_been_removed).enumerate() {
+            mapping.nodes[node.id] = Some(new_id);
+        }
+        for (new_id, edge) in self.edges.iter().filter(|edge| !edge.has_been_removed).enumerate() {
+            mapping.edges[edge.id] = Some(new_id);
+        }
+
+        self.nodes.retain(|node| !node.has_been_removed);
+        for node in self.nodes.iter_mut() {
+            node.id = mapping.nodes[node.id].unwrap();
+            for connection in node.connections.iter_mut() {
+                connection.to_node_id = mapping.nodes[connection.to_node_id].unwrap();
+                connection.edge_id = mapping.edges[connection.edge_id].unwrap();
+            }
+        }
+
+        self.edges.retain(|edge| !edge.has_been_removed);
+        for edge in self.edges.iter_mut() {
+            edge.id = mapping.edges[edge.id].unwrap();
+            edge.from = mapping.nodes[edge.from].unwrap();
+            edge.to = mapping.nodes[edge.to].unwrap();
+        }
+
+        mapping
+    }
+
+    // Ids of the edges starting or ending at the node, not removed yet
+    fn node_edge_ids(&self, node_id: usize) -> Vec<usize> {
+        self.edges
+            .iter()
+            .filter(|edge| !edge.has_been_removed && (edge.from == node_id || edge.to == node_id))
+            .map(|edge| edge.id)
+            .collect()
+    }
+
+    fn find_parallel_edge(&self, from: usize, to: usize, is_bidirectional: bool) -> Option<usize> {
+        self.nodes[from].connections
+            .iter()
+            .filter(|connection| connection.to_node_id == to)
+            .map(|connection| &self.edges[connection.edge_id])
+            .find(|edge| edge.is_bidirectional == is_bidirectional && (is_bidirectional || edge.from == from))
+            .map(|edge| edge.id)
+    }
+
+    fn restore_edge(&mut self, edge_id: usize) {
+        let edge = &mut self.edges[edge_id];
+        edge.has_been_removed = false;
+
+        self.nodes[edge.from].connections.push(Connection {
+            to_node_id: edge.to,
+            edge_id,
+        });
+        if edge.is_bidirectional {
+            self.nodes[edge.to].connections.push(Connection {
+                to_node_id: edge.from,
+                edge_id,
+            });
+        }
     }
 
     pub fn remove_edge_from_to(&mut self, from: usize, to: usize) {
@@ -139,6 +271,7 @@ impl<N, E> Graph<N, E> {
                 id: node.id,
                 value: map_node(&node.value),
                 connections: node.connections.clone(),
+                has_been_removed: node.has_been_removed,
             });
         }
 
@@ -164,11 +297,30 @@ impl<N, E> Graph<N, E> {
     }
 }
 
+// Old to new ids after `Graph::compact`, `None` for removed nodes and edges
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompactMapping {
+    pub nodes: Vec<Option<usize>>,
+    pub edges: Vec<Option<usize>>,
+}
+
+fn get_two_mut<T>(slice: &mut [T], a: usize, b: usize) -> (&mut T, &mut T) {
+    assert_ne!(a, b);
+    if a < b {
+        let (left, right) = slice.split_at_mut(b);
+        (&mut left[a], &mut right[0])
+    } else {
+        let (left, right) = slice.split_at_mut(a);
+        (&mut right[0], &mut left[b])
+    }
+}
+
 #[derive(Clone)]
 pub struct Node<N> {
     id: usize,
     value: N,
     connections: Vec<Connection>,
+    has_been_removed: bool,
 }
 
 #[allow(dead_code)]
@@ -188,6 +340,10 @@ impl<N> Node<N> {
     pub fn connections(&self) -> &[Connection] {
         &self.connections
     }
+
+    pub fn has_been_removed(&self) -> bool {
+        self.has_been_removed
+    }
 }
 
 #[derive(Clone)]
@@ -245,4 +401,96 @@ impl<E> Edge<E> {
     pub fn value_mut(&mut self) -> &mut E {
         &mut self.value
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_graph(len: usize, edges: &[(usize, usize, u32)]) -> Graph<usize, u32> {
+        let mut graph = Graph::new();
+        for id in 0..len {
+            graph.create_node(id);
+        }
+        for (from, to, value) in edges.iter() {
+            graph.create_edge(*from, *to, true, *value);
+        }
+        graph
+    }
+
+    fn neighbours(graph: &Graph<usize, u32>, node_id: usize) -> Vec<(usize, u32)> {
+        let mut neighbours: Vec<(usize, u32)> = graph.get_node(node_id)
+            .connections()
+            .iter()
+            .map(|connection| (connection.to_node_id(), *graph.get_edge(connection.edge_id()).value()))
+            .collect();
+        neighbours.sort();
+        neighbours
+    }
+
+    #[test]
+    fn test_remove_node_and_compact() {
+        let mut graph = create_graph(4, &[(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 0, 4)]);
+        graph.create_edge(3, 1, false, 5);
+        graph.remove_node(1);
+        assert!(graph.get_node(1).has_been_removed());
+        assert_eq!(neighbours(&graph, 0), vec![(3, 4)]);
+        assert_eq!(neighbours(&graph, 3), vec![(0, 4), (2, 3)]);
+
+        let mapping = graph.compact();
+        assert_eq!(mapping.nodes, vec![Some(0), None, Some(1), Some(2)]);
+        assert_eq!(mapping.edges, vec![None, None, Some(0), Some(1), None]);
+        assert_eq!(graph.nodes_len(), 3);
+        assert_eq!(graph.get_node(2).value(), &3);
+        assert_eq!(neighbours(&graph, 2), vec![(0, 4), (1, 3)]);
+        assert_eq!(graph.get_edge(1).id(), 1);
+    }
+
+    #[test]
+    fn test_removed_nodes_are_skipped() {
+        // Path 0-1-2-3-4 without its middle node
+        let mut graph = create_graph(5, &[(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 4, 1)]);
+        graph.remove_node(2);
+        assert_eq!(graph.connected_components(), vec![vec![0, 1], vec![3, 4]]);
+        assert!(graph.articulation_points().is_empty());
+        assert_eq!(graph.bridges(), vec![0, 3]);
+
+        let cut = graph.global_min_cut(|weight| u64::from(*weight)).unwrap();
+        assert_eq!(cut.value, 0);
+        assert!(!cut.side.contains(&2));
+
+        let style = GraphStyle::new();
+        assert!(!graph.to_dot(&style).contains("n2 "));
+        assert!(!graph.to_mermaid(&style).contains("n2["));
+
+        // Directed path 0 -> 1 -> 2 without its middle node
+        let mut graph: Graph<(), ()> = Graph::new();
+        for _ in 0..3 {
+            graph.create_node(());
+        }
+        graph.create_edge(0, 1, false, ());
+        graph.create_edge(1, 2, false, ());
+        graph.remove_node(1);
+        assert_eq!(graph.topological_sort(), Ok(vec![2, 0]));
+        assert_eq!(graph.strongly_connected_components(), vec![vec![0], vec![2]]);
+    }
+
+    #[test]
+    fn test_contract_nodes() {
+        // Square 0-1-2-3 with the diagonal 1-3
+        let edges = [(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 0, 4), (1, 3, 5)];
+
+        let mut graph = create_graph(4, &edges);
+        graph.contract_nodes(0, 1);
+        assert_eq!(neighbours(&graph, 0), vec![(2, 2), (3, 4), (3, 5)]);
+        assert_eq!(neighbours(&graph, 2), vec![(0, 2), (3, 3)]);
+        assert!(neighbours(&graph, 1).is_empty());
+
+        let mut graph = create_graph(4, &edges);
+        graph.contract_nodes_with(0, 1, |value, other| *value += other);
+        assert_eq!(neighbours(&graph, 0), vec![(2, 2), (3, 9)]);
+        assert_eq!(neighbours(&graph, 3), vec![(0, 9), (2, 3)]);
+        graph.compact();
+        assert_eq!(graph.edges_len(), 3);
+    }
+}
@@ -0,0 +1,293 @@
+use super::Graph;
+
+const UNVISITED: usize = usize::MAX;
+
+#[allow(dead_code)]
+impl<N, E> Graph<N, E> {
+    // Neighbours ignoring edge directions, as (node id, edge id)
+    pub(super) fn undirected_neighbours(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut neighbours = vec![Vec::new(); self.nodes.len()];
+        for edge in self.edges.iter().filter(|edge| !edge.has_been_removed) {
+            neighbours[edge.from].push((edge.to, edge.id));
+            if edge.from != edge.to {
+                neighbours[edge.to].push((edge.from, edge.id));
+            }
+        }
+        neighbours
+    }
+
+    // Weakly connected components, each one sorted by node id
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let neighbours = self.undirected_neighbours();
+        let mut visited = vec![false; self.nodes.len()];
+        let mut components = Vec::new();
+
+        for start in 0..self.nodes.len() {
+            if self.nodes[start].has_been_removed || visited[start] {
+                continue;
+            }
+
+            let mut component = Vec::new();
+            let mut stack = vec![start];
+            visited[start] = true;
+            while let Some(node_id) = stack.pop() {
+                component.push(node_id);
+                for &(to_node_id, _) in neighbours[node_id].iter() {
+                    if !visited[to_node_id] {
+                        visited[to_node_id] = true;
+                        stack.push(to_node_id);
+                    }
+                }
+            }
+            component.sort();
+            components.push(component);
+        }
+
+        components
+    }
+
+    // Follows edge directions, bidirectional edges count as a cycle.
+    // On failure returns the nodes of a cycle, in order.
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Vec<usize>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State { New, InProgress, Done }
+
+        let mut states = vec![State::New; self.nodes.len()];
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        for start in 0..self.nodes.len() {
+            if self.nodes[start].has_been_removed || states[start] != State::New {
+                continue;
+            }
+
+            // Stack of (node id, index of the next connection to visit)
+            let mut stack = vec![(start, 0)];
+            states[start] = State::InProgress;
+            while let Some((node_id, connection_index)) = stack.last_mut() {
+                let node_id = *node_id;
+                let Some(connection) = self.nodes[node_id].connections.get(*connection_index) else {
+                    states[node_id] = State::Done;
+                    order.push(node_id);
+                    stack.pop();
+                    continue;
+                };
+                *connection_index += 1;
+
+                let to_node_id = connection.to_node_id;
+                let is_parent_edge = self.edges[connection.edge_id].is_bidirectional
+                    && stack.len() >= 2
+                    && stack[stack.len() - 2].0 == to_node_id;
+                match states[to_node_id] {
+                    State::New => {
+                        states[to_node_id] = State::InProgress;
+                        stack.push((to_node_id, 0));
+                    },
+                    State::InProgress if is_parent_edge => return Err(vec![to_node_id, node_id]),
+                    State::InProgress => {
+                        let cycle_start = stack.iter().position(|(id, _)| *id == to_node_id).unwrap();
+                        return Err(stack[cycle_start..].iter().map(|(id, _)| *id).collect());
+                    },
+                    State::Done => (),
+                }
+            }
+        }
+
+        order.reverse();
+        Ok(order)
+    }
+
+    // Tarjan's algorithm, components are listed in reverse topological order
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let len = self.nodes.len();
+        let mut indices = vec![UNVISITED; len];
+        let mut low_links = vec![0; len];
+        let mut on_stack = vec![false; len];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0;
+
+        for start in 0..len {
+            if self.nodes[start].has_been_removed || indices[start] != UNVISITED {
+                continue;
+            }
+
+            let mut call_stack = vec![(start, 0)];
+            indices[start] = next_index;
+            low_links[start] = next_index;
+            next_index += 1;
+            stack.push(start);
+            on_stack[start] = true;
+
+            while let Some((node_id, connection_index)) = call_stack.last_mut() {
+                let node_id = *node_id;
+                if let Some(connection) = self.nodes[node_id].connections.get(*connection_index) {
+                    *connection_index += 1;
+                    let to_node_id = connection.to_node_id;
+                    if indices[to_node_id] == UNVISITED {
+                        indices[to_node_id] = next_index;
+                        low_links[to_node_id] = next_index;
+                        next_index += 1;
+                        stack.push(to_node_id);
+                        on_stack[to_node_id] = true;
+                        call_stack.push((to_node_id, 0));
+                    } else if on_stack[to_node_id] {
+                        low_links[node_id] = low_links[node_id].min(indices[to_node_id]);
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some((parent_id, _)) = call_stack.last() {
+                    low_links[*parent_id] = low_links[*parent_id].min(low_links[node_id]);
+                }
+
+                if low_links[node_id] == indices[node_id] {
+                    let mut component = Vec::new();
+                    loop {
+                        let id = stack.pop().unwrap();
+                        on_stack[id] = false;
+                        component.push(id);
+                        if id == node_id {
+                            break;
+                        }
+                    }
+                    component.sort();
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    // Nodes whose removal disconnects their component, ignoring edge directions
+    pub fn articulation_points(&self) -> Vec<usize> {
+        let (articulation_points, _) = self.cut_vertices_and_bridges();
+        articulation_points
+    }
+
+    // Edge ids whose removal disconnects their component, ignoring edge directions
+    pub fn bridges(&self) -> Vec<usize> {
+        let (_, bridges) = self.cut_vertices_and_bridges();
+        bridges
+    }
+
+    fn cut_vertices_and_bridges(&self) -> (Vec<usize>, Vec<usize>) {
+        let neighbours = self.undirected_neighbours();
+        let len = self.nodes.len();
+        let mut indices = vec![UNVISITED; len];
+        let mut low_links = vec![0; len];
+        let mut is_articulation_point = vec![false; len];
+        let mut bridges = Vec::new();
+        let mut next_index = 0;
+
+        for start in 0..len {
+            if self.nodes[start].has_been_removed || indices[start] != UNVISITED {
+                continue;
+            }
+
+            // Stack of (node id, edge id used to reach it, index of the next neighbour to visit)
+            let mut call_stack = vec![(start, usize::MAX, 0)];
+            let mut root_children = 0;
+            indices[start] = next_index;
+            low_links[start] = next_index;
+            next_index += 1;
+
+            while let Some((node_id, parent_edge_id, neighbour_index)) = call_stack.last_mut() {
+                let (node_id, parent_edge_id) = (*node_id, *parent_edge_id);
+                if let Some(&(to_node_id, edge_id)) = neighbours[node_id].get(*neighbour_index) {
+                    *neighbour_index += 1;
+                    if edge_id == parent_edge_id {
+                        continue;
+                    }
+                    if indices[to_node_id] == UNVISITED {
+                        indices[to_node_id] = next_index;
+                        low_links[to_node_id] = next_index;
+                        next_index += 1;
+                        call_stack.push((to_node_id, edge_id, 0));
+                        if node_id == start {
+                            root_children += 1;
+                        }
+                    } else {
+                        low_links[node_id] = low_links[node_id].min(indices[to_node_id]);
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some((parent_id, _, _)) = call_stack.last() {
+                    let parent_id = *parent_id;
+                    low_links[parent_id] = low_links[parent_id].min(low_links[node_id]);
+                    if low_links[node_id] > indices[parent_id] {
+                        bridges.push(parent_edge_id);
+                    }
+                    if parent_id != start && low_links[node_id] >= indices[parent_id] {
+                        is_articulation_point[parent_id] = true;
+                    }
+                }
+            }
+
+            if root_children > 1 {
+                is_articulation_point[start] = true;
+            }
+        }
+
+        let articulation_points = (0..len).filter(|id| is_articulation_point[*id]).collect();
+        bridges.sort();
+        (articulation_points, bridges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_graph(len: usize, edges: &[(usize, usize)], is_bidirectional: bool) -> Graph<(), ()> {
+        let mut graph = Graph::new();
+        for _ in 0..len {
+            graph.create_node(());
+        }
+        for (from, to) in edges.iter() {
+            graph.create_edge(*from, *to, is_bidirectional, ());
+        }
+        graph
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let graph = create_graph(6, &[(0, 1), (2, 1), (3, 4)], false);
+        assert_eq!(graph.connected_components(), vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = create_graph(4, &[(2, 1), (1, 0), (3, 1), (2, 3)], false);
+        assert_eq!(graph.topological_sort(), Ok(vec![2, 3, 1, 0]));
+
+        let graph = create_graph(4, &[(0, 1), (1, 2), (2, 3), (3, 1)], false);
+        assert_eq!(graph.topological_sort(), Err(vec![1, 2, 3]));
+
+        let graph = create_graph(2, &[(0, 1)], true);
+        assert!(graph.topological_sort().is_err());
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = create_graph(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (5, 4)], false);
+        let mut components = graph.strongly_connected_components();
+        components.sort();
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn test_articulation_points_and_bridges() {
+        // Triangle 0-1-2, bridge 2-3, then triangle 3-4-5
+        let graph = create_graph(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)], true);
+        assert_eq!(graph.articulation_points(), vec![2, 3]);
+        assert_eq!(graph.bridges(), vec![3]);
+
+        // Parallel edges are not bridges
+        let graph = create_graph(2, &[(0, 1), (0, 1)], true);
+        assert!(graph.bridges().is_empty());
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::VecDeque;
+
+use super::Graph;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MinCut {
+    pub value: u64,
+    // Ids of the edges crossing the cut
+    pub edges: Vec<usize>,
+    // Ids of the nodes on the source side, sorted
+    pub side: Vec<usize>,
+}
+
+// Residual arcs are stored by pairs, so the reverse of arc `i` is `i ^ 1`
+struct Arc {
+    to: usize,
+    capacity: u64,
+}
+
+#[allow(dead_code)]
+impl<N, E> Graph<N, E> {
+    // Edmonds-Karp, bidirectional edges carry their capacity in both directions
+    pub fn max_flow(&self, source: usize, sink: usize, capacity: impl Fn(&E) -> u64) -> u64 {
+        self.min_cut(source, sink, capacity).value
+    }
+
+    pub fn min_cut(&self, source: usize, sink: usize, capacity: impl Fn(&E) -> u64) -> MinCut {
+        assert_ne!(source, sink, "source and sink must be different nodes");
+
+        let mut arcs = Vec::with_capacity(self.edges.len() * 2);
+        let mut node_arcs = vec![Vec::new(); self.nodes.len()];
+        for edge in self.edges.iter().filter(|edge| !edge.has_been_removed) {
+            let edge_capacity = capacity(&edge.value);
+            node_arcs[edge.from].push(arcs.len());
+            arcs.push(Arc { to: edge.to, capacity: edge_capacity });
+            node_arcs[edge.to].push(arcs.len());
+            arcs.push(Arc { to: edge.from, capacity: if edge.is_bidirectional { edge_capacity } else { 0 } });
+        }
+
+        let mut value = 0;
+        loop {
+            // Shortest augmenting path, `parent_arcs` holds the arc used to reach each node
+            let mut parent_arcs = vec![usize::MAX; self.nodes.len()];
+            let mut visited = vec![false; self.nodes.len()];
+            let mut queue = VecDeque::from([source]);
+            visited[source] = true;
+            while let Some(node_id) = queue.pop_front() {
+                if node_id == sink {
+                    break;
+                }
+                for &arc_index in node_arcs[node_id].iter() {
+                    let arc = &arcs[arc_index];
+                    if arc.capacity > 0 && !visited[arc.to] {
+                        visited[arc.to] = true;
+                        parent_arcs[arc.to] = arc_index;
+                        queue.push_back(arc.to);
+                    }
+                }
+            }
+
+            if !visited[sink] {
+                let side: Vec<usize> = (0..self.nodes.len()).filter(|id| visited[*id]).collect();
+                let edges = self.edges
+                    .iter()
+                    .filter(|edge| !edge.has_been_removed && visited[edge.from] != visited[edge.to])
+                    .filter(|edge| edge.is_bidirectional || visited[edge.from])
+                    .map(|edge| edge.id)
+                    .collect();
+                return MinCut { value, edges, side };
+            }
+
+            let mut bottleneck = u64::MAX;
+            let mut node_id = sink;
+            while node_id != source {
+                let arc_index = parent_arcs[node_id];
+                bottleneck = bottleneck.min(arcs[arc_index].capacity);
+                node_id = arcs[arc_index ^ 1].to;
+            }
+
+            let mut node_id = sink;
+            while node_id != source {
+                let arc_index = parent_arcs[node_id];
+                arcs[arc_index].capacity -= bottleneck;
+                arcs[arc_index ^ 1].capacity += bottleneck;
+                node_id = arcs[arc_index ^ 1].to;
+            }
+            value += bottleneck;
+        }
+    }
+
+    // Stoer-Wagner global minimum cut, ignoring edge directions. O(V^3), meant for small graphs.
+    // Returns None if the graph has less than 2 nodes left.
+    pub fn global_min_cut(&self, weight: impl Fn(&E) -> u64) -> Option<MinCut> {
+        let len = self.nodes.len();
+        let mut remaining: Vec<usize> = (0..len).filter(|id| !self.nodes[*id].has_been_removed).collect();
+        if remaining.len() < 2 {
+            return None;
+        }
+
+        let mut weights = vec![vec![0u64; len]; len];
+        for edge in self.edges.iter().filter(|edge| !edge.has_been_removed && edge.from != edge.to) {
+            let edge_weight = weight(&edge.value);
+            weights[edge.from][edge.to] += edge_weight;
+            weights[edge.to][edge.from] += edge_weight;
+        }
+
+        // Nodes merged into each remaining super node
+        let mut groups: Vec<Vec<usize>> = (0..len).map(|id| vec![id]).collect();
+        let mut best: Option<(u64, Vec<usize>)> = None;
+
+        while remaining.len() > 1 {
+            let mut connectivity = vec![0u64; len];
+            let mut added = vec![false; len];
+            let mut previous = remaining[0];
+            let mut last = remaining[0];
+            for _ in 0..remaining.len() {
+                let next = *remaining.iter()
+                    .filter(|id| !added[**id])
+                    .max_by_key(|id| connectivity[**id])
+                    .unwrap();
+                added[next] = true;
+                previous = last;
+                last = next;
+                for &id in remaining.iter() {
+                    connectivity[id] += weights[next][id];
+                }
+            }
+
+            let cut_value = connectivity[last];
+            if best.as_ref().is_none_or(|(value, _)| cut_value < *value) {
+                best = Some((cut_value, groups[last].clone()));
+            }
+
+            // Merge the last node into the previous one
+            let last_group = std::mem::take(&mut groups[last]);
+            groups[previous].extend(last_group);
+            for &id in remaining.iter() {
+                weights[previous][id] += weights[last][id];
+                weights[id][previous] = weights[previous][id];
+            }
+            weights[previous][previous] = 0;
+            remaining.retain(|id| *id != last);
+        }
+
+        let (value, mut side) = best.unwrap();
+        side.sort();
+        let mut in_side = vec![false; len];
+        side.iter().for_each(|id| in_side[*id] = true);
+        let edges = self.edges
+            .iter()
+            .filter(|edge| !edge.has_been_removed && in_side[edge.from] != in_side[edge.to])
+            .map(|edge| edge.id)
+            .collect();
+        Some(MinCut { value, edges, side })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two complete graphs 0-1-2-3 and 4-5-6-7 linked by 3-4 and 2-5
+    fn create_graph() -> Graph<(), u64> {
+        let mut graph = Graph::new();
+        for _ in 0..8 {
+            graph.create_node(());
+        }
+        for offset in [0, 4] {
+            for (from, to) in [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)] {
+                graph.create_edge(from + offset, to + offset, true, 1);
+            }
+        }
+        graph.create_edge(3, 4, true, 1);
+        graph.create_edge(2, 5, true, 1);
+        graph
+    }
+
+    #[test]
+    fn test_min_cut() {
+        let graph = create_graph();
+        let cut = graph.min_cut(0, 7, |capacity| *capacity);
+        assert_eq!(cut, MinCut { value: 2, edges: vec![12, 13], side: vec![0, 1, 2, 3] });
+        assert_eq!(graph.max_flow(0, 1, |capacity| *capacity), 3);
+
+        // Directed edges only carry flow forward
+        let mut graph = Graph::new();
+        for _ in 0..3 {
+            graph.create_node(());
+        }
+        graph.create_edge(0, 1, false, 3u64);
+        graph.create_edge(1, 2, false, 2);
+        graph.create_edge(2, 0, false, 5);
+        assert_eq!(graph.max_flow(0, 2, |capacity| *capacity), 2);
+        assert_eq!(graph.max_flow(2, 1, |capacity| *capacity), 3);
+    }
+
+    #[test]
+    fn test_global_min_cut() {
+        let graph = create_graph();
+        let cut = graph.global_min_cut(|weight| *weight).unwrap();
+        assert_eq!(cut.value, 2);
+        assert_eq!(cut.edges, vec![12, 13]);
+        assert!(cut.side == vec![0, 1, 2, 3] || cut.side == vec![4, 5, 6, 7]);
+
+        let mut graph: Graph<(), u64> = Graph::new();
+        graph.create_node(());
+        assert_eq!(graph.global_min_cut(|weight| *weight), None);
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use super::{Edge, Graph, Node};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+impl GraphFormat {
+    // Guess the format from the file extension
+    pub fn from_path(path: &str) -> Option<Self> {
+        match path.rsplit_once('.')?.1 {
+            "dot" | "gv" => Some(GraphFormat::Dot),
+            "mmd" | "mermaid" => Some(GraphFormat::Mermaid),
+            _ => None,
+        }
+    }
+}
+
+type LabelFn<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
+pub struct GraphStyle<'a, N, E> {
+    node_label: Option<LabelFn<'a, Node<N>>>,
+    edge_label: Option<LabelFn<'a, Edge<E>>>,
+    highlighted_nodes: HashSet<usize>,
+    highlighted_edges: HashSet<usize>,
+}
+
+#[allow(dead_code)]
+impl<'a, N, E> GraphStyle<'a, N, E> {
+    pub fn new() -> Self {
+        GraphStyle {
+            node_label: None,
+            edge_label: None,
+            highlighted_nodes: HashSet::new(),
+            highlighted_edges: HashSet::new(),
+        }
+    }
+
+    // Nodes are labeled by their id by default
+    pub fn node_label(mut self, f: impl Fn(&Node<N>) -> String + 'a) -> Self {
+        self.node_label = Some(Box::new(f));
+        self
+    }
+
+    pub fn edge_label(mut self, f: impl Fn(&Edge<E>) -> String + 'a) -> Self {
+        self.edge_label = Some(Box::new(f));
+        self
+    }
+
+    pub fn highlight_nodes(mut self, node_ids: &[usize]) -> Self {
+        self.highlighted_nodes.extend(node_ids);
+        self
+    }
+
+    pub fn highlight_edges(mut self, edge_ids: &[usize]) -> Self {
+        self.highlighted_edges.extend(edge_ids);
+        self
+    }
+
+    // Highlight the nodes of a path and the edges between them
+    pub fn highlight_path(mut self, graph: &Graph<N, E>, node_ids: &[usize]) -> Self {
+        self.highlighted_nodes.extend(node_ids);
+        for pair in node_ids.windows(2) {
+            if let Some(edge_id) = graph.find_edge(pair[0], pair[1]) {
+                self.highlighted_edges.insert(edge_id);
+            }
+        }
+        self
+    }
+
+    fn get_node_label(&self, node: &Node<N>) -> String {
+        match &self.node_label {
+            Some(node_label) => node_label(node),
+            None => node.id.to_string(),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<N: Display, E> GraphStyle<'_, N, E> {
+    // Nodes are labeled by their value
+    pub fn with_value_labels() -> Self {
+        GraphStyle::new().node_label(|node: &Node<N>| node.value.to_string())
+    }
+}
+
+impl<N, E> Default for GraphStyle<'_, N, E> {
+    fn default() -> Self {
+        GraphStyle::new()
+    }
+}
+
+#[allow(dead_code)]
+impl<N, E> Graph<N, E> {
+    pub fn export(&self, format: GraphFormat, style: &GraphStyle<N, E>) -> String {
+        match format {
+            GraphFormat::Dot => self.to_dot(style),
+            GraphFormat::Mermaid => self.to_mermaid(style),
+        }
+    }
+
+    pub fn to_dot(&self, style: &GraphStyle<N, E>) -> String {
+        const HIGHLIGHT: &str = "color=red, penwidth=2";
+
+        let mut s = "digraph {\n".to_string();
+        for node in self.nodes.iter().filter(|node| !node.has_been_removed) {
+            let mut attributes = vec![format!("label={}", dot_escape(&style.get_node_label(node)))];
+            if style.highlighted_nodes.contains(&node.id) {
+                attributes.push(HIGHLIGHT.to_string());
+            }
+            s += &format!("    n{} [{}];\n", node.id, attributes.join(", "));
+        }
+
+        for edge in self.edges.iter().filter(|edge| !edge.has_been_removed) {
+            let mut attributes = Vec::new();
+            if edge.is_bidirectional {
+                attributes.push("dir=none".to_string());
+            }
+            if let Some(edge_label) = &style.edge_label {
+                attributes.push(format!("label={}", dot_escape(&edge_label(edge))));
+            }
+            if style.highlighted_edges.contains(&edge.id) {
+                attributes.push(HIGHLIGHT.to_string());
+            }
+
+            s += &format!("    n{} -> n{}", edge.from, edge.to);
+            if !attributes.is_empty() {
+                s += &format!(" [{}]", attributes.join(", "));
+            }
+            s += ";\n";
+        }
+        s += "}\n";
+        s
+    }
+
+    pub fn to_mermaid(&self, style: &GraphStyle<N, E>) -> String {
+        const HIGHLIGHT: &str = "stroke:red,stroke-width:3px";
+
+        let mut s = "flowchart LR\n".to_string();
+        for node in self.nodes.iter().filter(|node| !node.has_been_removed) {
+            s += &format!("    n{}[\"{}\"]\n", node.id, mermaid_escape(&style.get_node_label(node)));
+        }
+
+        // Links are styled by their index in declaration order
+        let mut highlighted_links = Vec::new();
+        for (index, edge) in self.edges.iter().filter(|edge| !edge.has_been_removed).enumerate() {
+            let arrow = if edge.is_bidirectional { "---" } else { "-->" };
+            let label = match &style.edge_label {
+                Some(edge_label) => format!("|\"{}\"|", mermaid_escape(&edge_label(edge))),
+                None => String::new(),
+            };
+            s += &format!("    n{} {arrow}{label} n{}\n", edge.from, edge.to);
+            if style.highlighted_edges.contains(&edge.id) {
+                highlighted_links.push(index.to_string());
+            }
+        }
+
+        for node in self.nodes.iter().filter(|node| style.highlighted_nodes.contains(&node.id)) {
+            s += &format!("    style n{} {HIGHLIGHT}\n", node.id);
+        }
+        if !highlighted_links.is_empty() {
+            s += &format!("    linkStyle {} {HIGHLIGHT}\n", highlighted_links.join(","));
+        }
+        s
+    }
+}
+
+fn dot_escape(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+fn mermaid_escape(s: &str) -> String {
+    s.replace('"', "#quot;").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_graph() -> Graph<&'static str, u32> {
+        let mut graph = Graph::new();
+        let a = graph.create_node("a");
+        let b = graph.create_node("b\"");
+        let c = graph.create_node("c");
+        graph.create_edge(a, b, true, 1);
+        graph.create_edge(b, c, false, 2);
+        graph
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let graph = create_graph();
+        let style = GraphStyle::with_value_labels()
+            .edge_label(|edge: &Edge<u32>| edge.value().to_string())
+            .highlight_path(&graph, &[1, 2]);
+        assert_eq!(graph.to_dot(&style), concat!(
+            "digraph {\n",
+            "    n0 [label=\"a\"];\n",
+            "    n1 [label=\"b\\\"\", color=red, penwidth=2];\n",
+            "    n2 [label=\"c\", color=red, penwidth=2];\n",
+            "    n0 -> n1 [dir=none, label=\"1\"];\n",
+            "    n1 -> n2 [label=\"2\", color=red, penwidth=2];\n",
+            "}\n",
+        ));
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let mut graph = create_graph();
+        graph.create_edge(0, 2, false, 3);
+        graph.remove_edge(0);
+        let style = GraphStyle::new().highlight_edges(&[2]);
+        assert_eq!(graph.export(GraphFormat::Mermaid, &style), concat!(
+            "flowchart LR\n",
+            "    n0[\"0\"]\n",
+            "    n1[\"1\"]\n",
+            "    n2[\"2\"]\n",
+            "    n1 --> n2\n",
+            "    n0 --> n2\n",
+            "    linkStyle 1 stroke:red,stroke-width:3px\n",
+        ));
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(GraphFormat::from_path("graph.dot"), Some(GraphFormat::Dot));
+        assert_eq!(GraphFormat::from_path("out/graph.mmd"), Some(GraphFormat::Mermaid));
+        assert_eq!(GraphFormat::from_path("graph.txt"), None);
+        assert_eq!(GraphFormat::from_path("graph"), None);
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use crate::utils::{Array2D, Point2D};
+use super::Graph;
+
+const DIRECTIONS: [Point2D; 4] = [Point2D::RIGHT, Point2D::UP, Point2D::LEFT, Point2D::DOWN];
+
+// Maze compressed to its junctions, edges hold the corridor lengths
+#[derive(Clone)]
+pub struct GridGraph {
+    graph: Graph<Point2D, u64>,
+    ids: HashMap<Point2D, usize>,
+}
+
+#[allow(dead_code)]
+impl GridGraph {
+    // Nodes are the passable tiles with a number of passable neighbours other than 2, plus `extra_nodes`.
+    // `can_move(from, to)` is called for each step between adjacent passable tiles, a corridor becomes a
+    // directed edge if it can only be walked one way. Corridors looping back to their start are ignored.
+    pub fn new<T>(grid: &Array2D<T>, is_passable: impl Fn(&T) -> bool, can_move: impl Fn(Point2D, Point2D) -> bool, extra_nodes: &[Point2D]) -> Self {
+        let passable_neighbours = |pos: Point2D| DIRECTIONS
+            .iter()
+            .map(move |dir| pos + *dir)
+            .filter(|next_pos| grid.try_get(*next_pos).is_some_and(&is_passable));
+
+        let mut grid_graph = GridGraph {
+            graph: Graph::new(),
+            ids: HashMap::new(),
+        };
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let pos = Point2D::new(x, y);
+                if is_passable(&grid[pos]) && (passable_neighbours(pos).count() != 2 || extra_nodes.contains(&pos)) {
+                    grid_graph.ids.insert(pos, grid_graph.graph.create_node(pos));
+                }
+            }
+        }
+
+        for from in 0..grid_graph.graph.nodes_len() {
+            let start = *grid_graph.graph.get_node(from).value();
+            for first_pos in passable_neighbours(start) {
+                let (mut previous_pos, mut pos) = (start, first_pos);
+                let mut length = 1;
+                let mut can_walk_forward = can_move(previous_pos, pos);
+                let mut can_walk_backward = can_move(pos, previous_pos);
+                while !grid_graph.ids.contains_key(&pos) {
+                    let next_pos = passable_neighbours(pos).find(|next_pos| *next_pos != previous_pos).unwrap();
+                    can_walk_forward &= can_move(pos, next_pos);
+                    can_walk_backward &= can_move(next_pos, pos);
+                    (previous_pos, pos) = (pos, next_pos);
+                    length += 1;
+                }
+
+                // Two-way corridors are found from both ends, only keep one
+                let to = grid_graph.ids[&pos];
+                if from == to || !can_walk_forward || (can_walk_backward && from > to) {
+                    continue;
+                }
+                grid_graph.graph.create_edge(from, to, can_walk_backward, length);
+            }
+        }
+
+        grid_graph
+    }
+
+    pub fn node_id(&self, pos: Point2D) -> Option<usize> {
+        self.ids.get(&pos).copied()
+    }
+
+    pub fn graph(&self) -> &Graph<Point2D, u64> {
+        &self.graph
+    }
+
+    pub fn into_graph(self) -> Graph<Point2D, u64> {
+        self.graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_graph() {
+        let grid = Array2D::from_str_map("#.###\n#.>.#\n#.#.#\n#...#\n###.#", false, |_, c| Ok(c)).unwrap();
+        let is_passable = |c: &char| *c != '#';
+
+        let grid_graph = GridGraph::new(&grid, is_passable, |_, _| true, &[]);
+        let graph = grid_graph.graph();
+        assert_eq!(graph.nodes_len(), 4);
+        let start = grid_graph.node_id(Point2D::new(1, 0)).unwrap();
+        let junction = grid_graph.node_id(Point2D::new(1, 1)).unwrap();
+        assert_eq!(graph.find_edge(start, junction).map(|edge_id| *graph.get_edge(edge_id).value()), Some(1));
+        assert_eq!(graph.edges_len(), 4);
+
+        // The slope at (2, 1) can only be walked to the right
+        let grid_graph = GridGraph::new(&grid, is_passable, |from, to| grid[to] != '>' || to.x() > from.x(), &[]);
+        let graph = grid_graph.graph();
+        assert_eq!(graph.edges_len(), 4);
+        assert_eq!(graph.edges_iter().filter(|edge| !edge.is_bidirectional()).count(), 1);
+
+        let grid_graph = GridGraph::new(&grid, is_passable, |_, _| true, &[Point2D::new(2, 1)]);
+        assert_eq!(grid_graph.graph().nodes_len(), 5);
+        assert_eq!(grid_graph.graph().edges_len(), 5);
+    }
+}
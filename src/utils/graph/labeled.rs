@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use crate::solvers::{ParseErrorKind, ParseSolverError};
+use crate::solvers::parsing::{parse_lines, LineCursor};
+use super::Graph;
+
+// Adjacency line formats found in puzzle inputs
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdjacencyFormat {
+    // `a: b c d`
+    Colon,
+    // `a-b`
+    Dash,
+    // `a -> b, c`
+    Arrow,
+}
+
+// Graph whose nodes are labeled by unique names
+#[derive(Clone)]
+pub struct LabeledGraph<E> {
+    graph: Graph<String, E>,
+    ids: HashMap<String, usize>,
+}
+
+#[allow(dead_code)]
+impl<E> LabeledGraph<E> {
+    pub fn new() -> Self {
+        LabeledGraph {
+            graph: Graph::new(),
+            ids: HashMap::new(),
+        }
+    }
+
+    pub fn get_or_create_node(&mut self, label: &str) -> usize {
+        if let Some(node_id) = self.ids.get(label) {
+            return *node_id;
+        }
+
+        let node_id = self.graph.create_node(label.to_string());
+        self.ids.insert(label.to_string(), node_id);
+        node_id
+    }
+
+    pub fn create_edge(&mut self, from: &str, to: &str, is_bidirectional: bool, value: E) -> usize {
+        let from = self.get_or_create_node(from);
+        let to = self.get_or_create_node(to);
+        self.graph.create_edge(from, to, is_bidirectional, value)
+    }
+
+    pub fn node_id(&self, label: &str) -> Option<usize> {
+        self.ids.get(label).copied()
+    }
+
+    pub fn label(&self, node_id: usize) -> &str {
+        self.graph.get_node(node_id).value()
+    }
+
+    pub fn graph(&self) -> &Graph<String, E> {
+        &self.graph
+    }
+
+    pub fn graph_mut(&mut self) -> &mut Graph<String, E> {
+        &mut self.graph
+    }
+
+    pub fn into_graph(self) -> Graph<String, E> {
+        self.graph
+    }
+}
+
+#[allow(dead_code)]
+impl<E: Default> LabeledGraph<E> {
+    // One edge per listed neighbour, with a default value
+    pub fn parse_adjacency(s: &str, format: AdjacencyFormat, is_bidirectional: bool) -> Result<Self, ParseSolverError> {
+        let mut graph = LabeledGraph::new();
+        parse_lines(s, |line| {
+            if line.is_empty() {
+                return Ok(());
+            }
+
+            let (from, to) = match format {
+                AdjacencyFormat::Colon => (parse_label(line, Some(":"))?, parse_neighbours(line, None)?),
+                AdjacencyFormat::Dash => (parse_label(line, Some("-"))?, vec![parse_label(line, None)?]),
+                AdjacencyFormat::Arrow => (parse_label(line, Some("->"))?, parse_neighbours(line, Some(","))?),
+            };
+            line.finish()?;
+
+            for to in to {
+                graph.create_edge(from, to, is_bidirectional, E::default());
+            }
+            Ok(())
+        })?;
+        Ok(graph)
+    }
+}
+
+impl<E> Default for LabeledGraph<E> {
+    fn default() -> Self {
+        LabeledGraph::new()
+    }
+}
+
+// Label up to the delimiter, or the next token without delimiter
+fn parse_label<'a>(line: &mut LineCursor<'a>, delimiter: Option<&str>) -> Result<&'a str, ParseSolverError> {
+    let position = line.position();
+    let label = match delimiter {
+        Some(delimiter) => line.take_until(delimiter)?,
+        None => line.next_token()?,
+    };
+    if label.is_empty() {
+        return Err(line.error_at(position, ParseErrorKind::UnexpectedToken, "expected a node label"));
+    }
+    Ok(label)
+}
+
+// Neighbours separated by whitespace, or by the given separator
+fn parse_neighbours<'a>(line: &mut LineCursor<'a>, separator: Option<&str>) -> Result<Vec<&'a str>, ParseSolverError> {
+    let mut neighbours = Vec::new();
+    loop {
+        match separator {
+            Some(separator) if line.rest().contains(separator) => neighbours.push(parse_label(line, Some(separator))?),
+            Some(_) => {
+                neighbours.push(parse_label(line, None)?);
+                break;
+            },
+            None => {
+                neighbours.push(parse_label(line, None)?);
+                if line.is_empty() {
+                    break;
+                }
+            },
+        }
+    }
+    Ok(neighbours)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn neighbours(graph: &LabeledGraph<()>, label: &str) -> Vec<String> {
+        let node_id = graph.node_id(label).unwrap();
+        let mut labels: Vec<String> = graph.graph()
+            .get_node(node_id)
+            .connections()
+            .iter()
+            .map(|connection| graph.label(connection.to_node_id()).to_string())
+            .collect();
+        labels.sort();
+        labels
+    }
+
+    #[test]
+    fn test_parse_adjacency() {
+        let graph = LabeledGraph::<()>::parse_adjacency("jqt: rhn xhk\nrhn: xhk", AdjacencyFormat::Colon, true).unwrap();
+        assert_eq!(graph.graph().nodes_len(), 3);
+        assert_eq!(neighbours(&graph, "xhk"), vec!["jqt", "rhn"]);
+
+        let graph = LabeledGraph::<()>::parse_adjacency("start-A\nA-end\n", AdjacencyFormat::Dash, true).unwrap();
+        assert_eq!(neighbours(&graph, "A"), vec!["end", "start"]);
+
+        let graph = LabeledGraph::<()>::parse_adjacency("broadcaster -> a, b\na -> b", AdjacencyFormat::Arrow, false).unwrap();
+        assert_eq!(neighbours(&graph, "broadcaster"), vec!["a", "b"]);
+        assert_eq!(neighbours(&graph, "a"), vec!["b"]);
+        assert!(neighbours(&graph, "b").is_empty());
+        assert_eq!(graph.node_id("c"), None);
+    }
+
+    #[test]
+    fn test_parse_adjacency_errors() {
+        let err = LabeledGraph::<()>::parse_adjacency("a: b\nc d", AdjacencyFormat::Colon, true).err().unwrap();
+        assert_eq!(err.line(), Some(2));
+        assert!(LabeledGraph::<()>::parse_adjacency("a b", AdjacencyFormat::Dash, true).is_err());
+        assert!(LabeledGraph::<()>::parse_adjacency("a -> b,", AdjacencyFormat::Arrow, true).is_err());
+        assert!(LabeledGraph::<()>::parse_adjacency("-> b", AdjacencyFormat::Arrow, true).is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::thread;
+
+use super::Graph;
+
+// Node sets are stored as bitmasks
+const MAX_NODES: usize = 64;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LongestPath {
+    pub length: u64,
+    // Node ids from start to target, both included
+    pub nodes: Vec<usize>,
+}
+
+#[allow(dead_code)]
+impl<N, E> Graph<N, E> {
+    // Longest simple path by exhaustive search with pruning, for graphs of at most 64 nodes
+    pub fn longest_path(&self, start: usize, target: usize, weight: impl Fn(&E) -> u64) -> Option<LongestPath> {
+        let search = Search::new(self, target, weight);
+        let mut best = None;
+        let mut state = search.start_state(start);
+        search.dfs(&mut state, &mut best);
+        best
+    }
+
+    // Same as `longest_path`, the first levels of the search are split between threads
+    pub fn longest_path_parallel(&self, start: usize, target: usize, weight: impl Fn(&E) -> u64) -> Option<LongestPath> {
+        let search = Search::new(self, target, weight);
+        let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+
+        // Expand the search breadth first until there is enough work for every thread
+        let mut states = vec![search.start_state(start)];
+        while states.len() < threads * 8 && states.iter().any(|state| state.node_id != target) {
+            states = states
+                .into_iter()
+                .flat_map(|state| {
+                    if state.node_id == target {
+                        return vec![state];
+                    }
+                    search.moves(state.node_id, state.visited)
+                        .map(|(to_node_id, length)| {
+                            let mut next_state = state.clone();
+                            next_state.visit(to_node_id, length, search.max_lengths_in[to_node_id]);
+                            next_state
+                        })
+                        .collect()
+                })
+                .collect();
+        }
+
+        let next_index = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|_| scope.spawn(|| {
+                    let mut best = None;
+                    while let Some(state) = states.get(next_index.fetch_add(1, Ordering::Relaxed)) {
+                        search.dfs(&mut state.clone(), &mut best);
+                    }
+                    best
+                }))
+                .collect();
+
+            handles
+                .into_iter()
+                .filter_map(|handle| handle.join().unwrap())
+                .max_by_key(|best| best.length)
+        })
+    }
+}
+
+struct Search {
+    // (node id, length) of the edges leaving each node, keeping the longest of parallel edges
+    neighbours: Vec<Vec<(usize, u64)>>,
+    // Length of the longest edge entering each node
+    max_lengths_in: Vec<u64>,
+    target: usize,
+    // If the target can only be reached from a single node, that node must go straight to the target
+    last_junction: Option<usize>,
+    // Best length found by any thread plus one, 0 if none
+    best_length: AtomicU64,
+}
+
+#[derive(Clone)]
+struct State {
+    node_id: usize,
+    visited: u64,
+    length: u64,
+    // Maximum length left to gain, the sum of `max_lengths_in` over unvisited nodes
+    remaining_bound: u64,
+    nodes: Vec<usize>,
+}
+
+impl State {
+    fn visit(&mut self, node_id: usize, length: u64, max_length_in: u64) {
+        self.node_id = node_id;
+        self.visited |= 1 << node_id;
+        self.length += length;
+        self.remaining_bound -= max_length_in;
+        self.nodes.push(node_id);
+    }
+
+    fn unvisit(&mut self, previous_node_id: usize, length: u64, max_length_in: u64) {
+        self.visited &= !(1 << self.node_id);
+        self.node_id = previous_node_id;
+        self.length -= length;
+        self.remaining_bound += max_length_in;
+        self.nodes.pop();
+    }
+}
+
+impl Search {
+    fn new<N, E>(graph: &Graph<N, E>, target: usize, weight: impl Fn(&E) -> u64) -> Self {
+        let len = graph.nodes.len();
+        assert!(len <= MAX_NODES, "longest path search is limited to {MAX_NODES} nodes");
+
+        let mut neighbours: Vec<Vec<(usize, u64)>> = vec![Vec::new(); len];
+        let mut max_lengths_in = vec![0; len];
+        for node in graph.nodes.iter() {
+            for connection in node.connections.iter() {
+                let to_node_id = connection.to_node_id;
+                let length = weight(&graph.edges[connection.edge_id].value);
+                max_lengths_in[to_node_id] = max_lengths_in[to_node_id].max(length);
+                match neighbours[node.id].iter_mut().find(|(id, _)| *id == to_node_id) {
+                    Some((_, max_length)) => *max_length = (*max_length).max(length),
+                    None => neighbours[node.id].push((to_node_id, length)),
+                }
+            }
+        }
+
+        let mut predecessors = (0..len).filter(|id| *id != target && neighbours[*id].iter().any(|(to, _)| *to == target));
+        let last_junction = match (predecessors.next(), predecessors.next()) {
+            (Some(id), None) => Some(id),
+            _ => None,
+        };
+
+        Search {
+            neighbours,
+            max_lengths_in,
+            target,
+            last_junction,
+            best_length: AtomicU64::new(0),
+        }
+    }
+
+    fn start_state(&self, start: usize) -> State {
+        let total_bound: u64 = self.max_lengths_in.iter().sum();
+        State {
+            node_id: start,
+            visited: 1 << start,
+            length: 0,
+            remaining_bound: total_bound - self.max_lengths_in[start],
+            nodes: vec![start],
+        }
+    }
+
+    fn moves(&self, node_id: usize, visited: u64) -> impl Iterator<Item = (usize, u64)> + '_ {
+        let is_last_junction = self.last_junction == Some(node_id);
+        self.neighbours[node_id]
+            .iter()
+            .copied()
+            .filter(move |(to_node_id, _)| visited & (1 << to_node_id) == 0)
+            .filter(move |(to_node_id, _)| !is_last_junction || *to_node_id == self.target)
+    }
+
+    fn best_length(&self) -> Option<u64> {
+        self.best_length.load(Ordering::Relaxed).checked_sub(1)
+    }
+
+    fn dfs(&self, state: &mut State, best: &mut Option<LongestPath>) {
+        if state.node_id == self.target {
+            if self.best_length().is_none_or(|best_length| state.length > best_length) {
+                self.best_length.fetch_max(state.length + 1, Ordering::Relaxed);
+                *best = Some(LongestPath {
+                    length: state.length,
+                    nodes: state.nodes.clone(),
+                });
+            }
+            return;
+        }
+        if self.best_length().is_some_and(|best_length| state.length + state.remaining_bound <= best_length) {
+            return;
+        }
+
+        let node_id = state.node_id;
+        for (to_node_id, length) in self.moves(node_id, state.visited) {
+            let max_length_in = self.max_lengths_in[to_node_id];
+            state.visit(to_node_id, length, max_length_in);
+            self.dfs(state, best);
+            state.unvisit(node_id, length, max_length_in);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_graph() -> Graph<(), u64> {
+        // 5 can only be reached from 3
+        let mut graph = Graph::new();
+        for _ in 0..6 {
+            graph.create_node(());
+        }
+        for (from, to, length) in [(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 5, 1), (0, 2, 10), (1, 3, 1), (2, 4, 1), (4, 3, 5)] {
+            graph.create_edge(from, to, true, length);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_longest_path() {
+        let graph = create_graph();
+        let path = graph.longest_path(0, 5, |length| *length).unwrap();
+        assert_eq!(path, LongestPath { length: 17, nodes: vec![0, 2, 4, 3, 5] });
+        assert_eq!(graph.longest_path_parallel(0, 5, |length| *length), Some(path));
+
+        let mut graph = create_graph();
+        graph.create_node(());
+        assert_eq!(graph.longest_path(0, 6, |length| *length), None);
+        assert_eq!(graph.longest_path_parallel(0, 6, |length| *length), None);
+    }
+}
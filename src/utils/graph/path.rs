@@ -0,0 +1,181 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::Graph;
+
+const NO_PARENT: usize = usize::MAX;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortestPath {
+    pub cost: u64,
+    // Node ids from start to target, both included
+    pub nodes: Vec<usize>,
+}
+
+#[allow(dead_code)]
+impl<N, E> Graph<N, E> {
+    pub fn dijkstra(&self, start: usize, target: usize, weight: impl Fn(&E) -> u64) -> Option<ShortestPath> {
+        self.a_star(start, target, weight, |_| 0)
+    }
+
+    // The heuristic must never overestimate the remaining cost to the target
+    pub fn a_star(&self, start: usize, target: usize, weight: impl Fn(&E) -> u64, heuristic: impl Fn(usize) -> u64) -> Option<ShortestPath> {
+        let mut costs = vec![u64::MAX; self.nodes.len()];
+        let mut parents = vec![NO_PARENT; self.nodes.len()];
+        let mut open_set = BinaryHeap::new();
+
+        costs[start] = 0;
+        open_set.push(Reverse((heuristic(start), 0, start)));
+
+        while let Some(Reverse((_, cost, node_id))) = open_set.pop() {
+            if node_id == target {
+                return Some(ShortestPath {
+                    cost,
+                    nodes: reconstruct_path(&parents, start, target),
+                });
+            }
+            if cost > costs[node_id] {
+                continue;
+            }
+
+            for connection in self.nodes[node_id].connections.iter() {
+                let to_node_id = connection.to_node_id;
+                let new_cost = cost + weight(&self.edges[connection.edge_id].value);
+                if new_cost < costs[to_node_id] {
+                    costs[to_node_id] = new_cost;
+                    parents[to_node_id] = node_id;
+                    open_set.push(Reverse((new_cost + heuristic(to_node_id), new_cost, to_node_id)));
+                }
+            }
+        }
+
+        None
+    }
+
+    // Every edge costs 1
+    pub fn bfs(&self, start: usize, target: usize) -> Option<ShortestPath> {
+        let mut parents = vec![NO_PARENT; self.nodes.len()];
+        let mut visited = vec![false; self.nodes.len()];
+        let mut queue = VecDeque::from([(start, 0)]);
+        visited[start] = true;
+
+        while let Some((node_id, cost)) = queue.pop_front() {
+            if node_id == target {
+                return Some(ShortestPath {
+                    cost,
+                    nodes: reconstruct_path(&parents, start, target),
+                });
+            }
+
+            for connection in self.nodes[node_id].connections.iter() {
+                let to_node_id = connection.to_node_id;
+                if !visited[to_node_id] {
+                    visited[to_node_id] = true;
+                    parents[to_node_id] = node_id;
+                    queue.push_back((to_node_id, cost + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    // Edges weigh either 0 or 1, cheaper than Dijkstra as the queue stays sorted without a heap
+    pub fn bfs_0_1(&self, start: usize, target: usize, weight: impl Fn(&E) -> u64) -> Option<ShortestPath> {
+        let mut costs = vec![u64::MAX; self.nodes.len()];
+        let mut parents = vec![NO_PARENT; self.nodes.len()];
+        let mut queue = VecDeque::from([(start, 0)]);
+        costs[start] = 0;
+
+        while let Some((node_id, cost)) = queue.pop_front() {
+            if node_id == target {
+                return Some(ShortestPath {
+                    cost,
+                    nodes: reconstruct_path(&parents, start, target),
+                });
+            }
+            if cost > costs[node_id] {
+                continue;
+            }
+
+            for connection in self.nodes[node_id].connections.iter() {
+                let to_node_id = connection.to_node_id;
+                let edge_weight = weight(&self.edges[connection.edge_id].value);
+                debug_assert!(edge_weight <= 1, "0-1 BFS edge weight must be 0 or 1, got {edge_weight}");
+
+                let new_cost = cost + edge_weight;
+                if new_cost < costs[to_node_id] {
+                    costs[to_node_id] = new_cost;
+                    parents[to_node_id] = node_id;
+                    if edge_weight == 0 {
+                        queue.push_front((to_node_id, new_cost));
+                    } else {
+                        queue.push_back((to_node_id, new_cost));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+fn reconstruct_path(parents: &[usize], start: usize, target: usize) -> Vec<usize> {
+    let mut nodes = vec![target];
+    let mut node_id = target;
+    while node_id != start {
+        node_id = parents[node_id];
+        nodes.push(node_id);
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1- 1 -1- 2 -1- 3
+    //  \----------5----/
+    //        4 (isolated)
+    fn create_graph() -> Graph<(), u64> {
+        let mut graph = Graph::new();
+        for _ in 0..5 {
+            graph.create_node(());
+        }
+        graph.create_edge(0, 1, true, 1);
+        graph.create_edge(1, 2, true, 1);
+        graph.create_edge(2, 3, true, 1);
+        graph.create_edge(0, 3, true, 5);
+        graph
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let graph = create_graph();
+
+        let path = graph.dijkstra(0, 3, |weight| *weight).unwrap();
+        assert_eq!(path, ShortestPath { cost: 3, nodes: vec![0, 1, 2, 3] });
+        let path = graph.a_star(0, 3, |weight| *weight, |node_id| 3 - node_id.min(3) as u64).unwrap();
+        assert_eq!(path, ShortestPath { cost: 3, nodes: vec![0, 1, 2, 3] });
+        let path = graph.bfs(0, 3).unwrap();
+        assert_eq!(path, ShortestPath { cost: 1, nodes: vec![0, 3] });
+        let path = graph.bfs_0_1(0, 3, |weight| if *weight == 1 { 0 } else { 1 }).unwrap();
+        assert_eq!(path, ShortestPath { cost: 0, nodes: vec![0, 1, 2, 3] });
+
+        assert_eq!(graph.dijkstra(2, 2, |weight| *weight), Some(ShortestPath { cost: 0, nodes: vec![2] }));
+        assert_eq!(graph.dijkstra(0, 4, |weight| *weight), None);
+        assert_eq!(graph.bfs(0, 4), None);
+    }
+
+    #[test]
+    fn test_directed_and_removed_edges() {
+        let mut graph = create_graph();
+        graph.create_edge(3, 4, false, 1);
+        assert_eq!(graph.bfs(0, 4).map(|path| path.cost), Some(2));
+        assert_eq!(graph.bfs(4, 0), None);
+
+        graph.remove_edge(graph.find_edge(1, 2).unwrap());
+        assert_eq!(graph.dijkstra(0, 2, |weight| *weight).map(|path| path.nodes), Some(vec![0, 3, 2]));
+    }
+}
@@ -0,0 +1,147 @@
+use std::ops::Index;
+
+use crate::utils::{ArrayMD, BoundMD, Point};
+
+// Unbounded grid stored in an array that grows to contain every cell that was set, every other cell has the background value.
+// Meant for dense data, see `SparseGrid` when only a few cells are set.
+#[derive(Clone)]
+pub struct InfiniteGrid<const D: usize, T> {
+    array: ArrayMD<D, T>,
+    // Coords of the first array cell
+    origin: Point<D>,
+    background: T,
+    // Smallest bound containing every cell that was set
+    bound: Option<BoundMD<D>>,
+}
+
+#[allow(dead_code)]
+impl<const D: usize, T: Clone> InfiniteGrid<D, T> {
+    pub fn new(background: T) -> Self {
+        InfiniteGrid::with_capacity(BoundMD::default(), background)
+    }
+
+    // Storage is allocated for the whole bound upfront
+    pub fn with_capacity(bound: BoundMD<D>, background: T) -> Self {
+        InfiniteGrid {
+            array: ArrayMD::filled(bound.sizes(), background.clone()),
+            origin: bound.start(),
+            background,
+            bound: None,
+        }
+    }
+
+    pub fn insert(&mut self, coords: impl Into<Point<D>>, value: T) {
+        *self.get_mut(coords) = value;
+    }
+
+    pub fn get_mut(&mut self, coords: impl Into<Point<D>>) -> &mut T {
+        let coords = coords.into();
+        // The array always contains `bound`
+        match &mut self.bound {
+            Some(bound) if bound.contains(coords) => (),
+            Some(bound) => {
+                bound.append_point(coords);
+                self.reserve(coords);
+            },
+            None => {
+                self.bound = Some(BoundMD::new(coords, Point::ONE));
+                self.reserve(coords);
+            },
+        }
+        &mut self.array[coords - self.origin]
+    }
+
+    // Grows the array to contain the coords, at least doubling its size along the dimensions that grow
+    fn reserve(&mut self, coords: Point<D>) {
+        let storage = self.storage();
+        if storage.contains(coords) {
+            return;
+        }
+
+        let mut new_storage = storage;
+        if storage.is_empty() {
+            new_storage = BoundMD::new(coords, Point::ONE);
+        } else {
+            for d in 0..D {
+                if coords[d] < storage.start()[d] {
+                    new_storage.set_min_d(d, coords[d] - storage.sizes()[d]);
+                } else if coords[d] >= storage.end()[d] {
+                    new_storage.set_end_d(d, coords[d] + 1 + storage.sizes()[d]);
+                }
+            }
+        }
+
+        let mut array = ArrayMD::filled(new_storage.sizes(), self.background.clone());
+        if !storage.is_empty() {
+            self.array.copy_to_with_offset(&mut array, storage.start() - new_storage.start());
+        }
+        self.array = array;
+        self.origin = new_storage.start();
+    }
+}
+
+#[allow(dead_code)]
+impl<const D: usize, T> InfiniteGrid<D, T> {
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    pub fn bound(&self) -> Option<BoundMD<D>> {
+        self.bound
+    }
+
+    pub fn get(&self, coords: impl Into<Point<D>>) -> &T {
+        self.array.try_get(coords.into() - self.origin).unwrap_or(&self.background)
+    }
+
+    // Cells inside `bound`, including the ones never set
+    pub fn iter(&self) -> impl Iterator<Item = (Point<D>, &T)> {
+        self.bound
+            .into_iter()
+            .flat_map(|bound| bound.iter_points())
+            .map(|coords| (coords, &self.array[coords - self.origin]))
+    }
+
+    fn storage(&self) -> BoundMD<D> {
+        BoundMD::new(self.origin, self.array.sizes())
+    }
+}
+
+impl<const D: usize, I: Into<Point<D>>, T> Index<I> for InfiniteGrid<D, T> {
+    type Output = T;
+
+    fn index(&self, coords: I) -> &T {
+        self.get(coords)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Point2D;
+
+    #[test]
+    fn test_infinite_grid() {
+        let mut grid: InfiniteGrid<2, i32> = InfiniteGrid::new(0);
+        assert_eq!(grid.bound(), None);
+        assert_eq!(grid[[1_000, -5]], 0);
+
+        grid.insert([2, 3], 5);
+        *grid.get_mut([-1, 7]) += 2;
+        *grid.get_mut([2, 3]) += 1;
+        *grid.get_mut([-4, 3]) += 1;
+        assert_eq!(grid[[2, 3]], 6);
+        assert_eq!(grid[Point2D::new(-1, 7)], 2);
+        assert_eq!(grid[[-4, 3]], 1);
+        assert_eq!(grid[[0, 0]], 0);
+        assert_eq!(grid.bound(), Some(BoundMD::from_min_max([-4, 3], [2, 7])));
+
+        let cells: Vec<(Point2D, i32)> = grid.iter().filter(|(_, value)| **value != 0).map(|(coords, value)| (coords, *value)).collect();
+        assert_eq!(cells, vec![(Point2D::new(-4, 3), 1), (Point2D::new(2, 3), 6), (Point2D::new(-1, 7), 2)]);
+        assert_eq!(grid.iter().count(), 7 * 5);
+
+        let grid: InfiniteGrid<2, bool> = InfiniteGrid::with_capacity(BoundMD::new([0, 0], [3, 3]), true);
+        assert!(grid[[1, 1]] && grid[[-10, 10]]);
+        assert_eq!(grid.iter().count(), 0);
+    }
+}
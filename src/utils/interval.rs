@@ -0,0 +1,208 @@
+use crate::utils::Range;
+
+// Sorted ranges, neither overlapping nor adjacent
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IntervalSet {
+    ranges: Vec<Range>,
+}
+
+#[allow(dead_code)]
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    pub fn from_ranges(ranges: impl IntoIterator<Item = Range>) -> Self {
+        let mut set = IntervalSet {
+            ranges: ranges.into_iter().collect(),
+        };
+        set.normalize();
+        set
+    }
+
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // Number of values in the set
+    pub fn len(&self) -> i64 {
+        self.ranges.iter().map(|range| range.distance()).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|range| range.min()[0])
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|range| range.max()[0])
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|range| range.end()[0] <= value);
+        self.ranges.get(index).is_some_and(|range| range.contains(value))
+    }
+
+    pub fn insert(&mut self, range: Range) {
+        self.ranges.push(range);
+        self.normalize();
+    }
+
+    pub fn remove(&mut self, range: Range) {
+        self.ranges = self.ranges
+            .iter()
+            .flat_map(|existing_range| existing_range.subtract(&range))
+            .collect();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        IntervalSet::from_ranges(self.ranges.iter().chain(other.ranges.iter()).copied())
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.ranges.iter() {
+            difference.remove(*range);
+        }
+        difference
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let ranges: Vec<Range> = self.ranges
+            .iter()
+            .flat_map(|range| other.ranges.iter().filter_map(|other_range| range.and(other_range)))
+            .collect();
+        IntervalSet::from_ranges(ranges)
+    }
+
+    // Values lower than `value`, then the others
+    pub fn split_at(&self, value: i64) -> (Self, Self) {
+        let mut lower = Vec::new();
+        let mut upper = Vec::new();
+        for range in self.ranges.iter() {
+            let (range_lower, range_upper) = range.split_d(0, value);
+            lower.extend(range_lower);
+            upper.extend(range_upper);
+        }
+        (IntervalSet { ranges: lower }, IntervalSet { ranges: upper })
+    }
+
+    // Moves every value by `offset`
+    pub fn shift(&self, offset: i64) -> Self {
+        let ranges = self.ranges
+            .iter()
+            .map(|range| Range::new(range.start() + offset, range.sizes()))
+            .collect();
+        IntervalSet { ranges }
+    }
+
+    fn normalize(&mut self) {
+        self.ranges.retain(|range| !range.is_empty());
+        self.ranges.sort_by_key(|range| range.start()[0]);
+
+        let mut merged: Vec<Range> = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.iter() {
+            match merged.last_mut() {
+                Some(last) if range.start()[0] <= last.end()[0] => {
+                    let end = i64::max(last.end()[0], range.end()[0]);
+                    last.set_end_d(0, end);
+                },
+                _ => merged.push(*range),
+            }
+        }
+        self.ranges = merged;
+    }
+}
+
+impl From<Range> for IntervalSet {
+    fn from(range: Range) -> Self {
+        IntervalSet::from_ranges([range])
+    }
+}
+
+// Piecewise offset mapping, values outside of every source range are left unchanged
+#[derive(Clone, Debug, Default)]
+pub struct RangeMap {
+    // Source ranges with the offset to apply, they must not overlap
+    entries: Vec<(Range, i64)>,
+}
+
+#[allow(dead_code)]
+impl RangeMap {
+    pub fn new() -> Self {
+        RangeMap { entries: Vec::new() }
+    }
+
+    pub fn insert(&mut self, source: Range, offset: i64) {
+        debug_assert!(self.entries.iter().all(|(other_source, _)| other_source.and(&source).is_none()));
+        self.entries.push((source, offset));
+    }
+
+    // Maps `source` to the range of the same size starting at `destination_start`
+    pub fn insert_destination(&mut self, source: Range, destination_start: i64) {
+        self.insert(source, destination_start - source.start()[0]);
+    }
+
+    pub fn map(&self, value: i64) -> i64 {
+        self.entries
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let mut unmapped = set.clone();
+        let mut mapped = IntervalSet::new();
+        for (source, offset) in self.entries.iter() {
+            let inside = unmapped.intersection(&IntervalSet::from(*source));
+            if inside.is_empty() {
+                continue;
+            }
+            mapped = mapped.union(&inside.shift(*offset));
+            unmapped.remove(*source);
+        }
+        mapped.union(&unmapped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet {
+        IntervalSet::from_ranges(ranges.iter().map(|(min, max)| Range::from_min_max(*min, *max)))
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let a = set(&[(5, 9), (0, 2), (3, 3), (20, 30)]);
+        assert_eq!(a, set(&[(0, 3), (5, 9), (20, 30)]));
+        assert_eq!(a.len(), 4 + 5 + 11);
+        assert_eq!((a.min(), a.max()), (Some(0), Some(30)));
+        assert!(a.contains(7) && a.contains(20) && !a.contains(4) && !a.contains(31));
+
+        let b = set(&[(2, 6), (25, 40)]);
+        assert_eq!(a.union(&b), set(&[(0, 9), (20, 40)]));
+        assert_eq!(a.difference(&b), set(&[(0, 1), (7, 9), (20, 24)]));
+        assert_eq!(a.intersection(&b), set(&[(2, 3), (5, 6), (25, 30)]));
+        assert_eq!(a.split_at(8), (set(&[(0, 3), (5, 7)]), set(&[(8, 9), (20, 30)])));
+        assert_eq!(a.shift(-10), set(&[(-10, -7), (-5, -1), (10, 20)]));
+    }
+
+    #[test]
+    fn test_range_map() {
+        // Example of 2023 day 5, seed-to-soil map
+        let mut map = RangeMap::new();
+        map.insert_destination(Range::new(98, 2), 50);
+        map.insert_destination(Range::new(50, 48), 52);
+        assert_eq!(map.map(79), 81);
+        assert_eq!(map.map(99), 51);
+        assert_eq!(map.map(10), 10);
+
+        let seeds = set(&[(79, 92), (96, 100)]);
+        assert_eq!(map.map_set(&seeds), set(&[(50, 51), (81, 94), (98, 100)]));
+    }
+}
@@ -96,6 +96,30 @@ impl<const D: usize> Point<D> {
     pub fn as_mut_slice(&mut self) -> &mut [i64; D] {
         &mut self.coords
     }
+
+    // The 2 * D neighbours sharing a face (von Neumann neighbourhood)
+    pub fn von_neumann_neighbours(self) -> impl Iterator<Item = Self> {
+        (0..2 * D).map(move |index| {
+            let mut neighbour = self;
+            neighbour.coords[index / 2] += if index % 2 == 0 { -1 } else { 1 };
+            neighbour
+        })
+    }
+
+    // The 3^D - 1 neighbours sharing a face, an edge or a corner (Moore neighbourhood)
+    pub fn moore_neighbours(self) -> impl Iterator<Item = Self> {
+        let count = 3usize.pow(D as u32);
+        (0..count)
+            .filter(move |index| *index != count / 2)
+            .map(move |mut index| {
+                let mut neighbour = self;
+                for coord in neighbour.coords.iter_mut() {
+                    *coord += (index % 3) as i64 - 1;
+                    index /= 3;
+                }
+                neighbour
+            })
+    }
 }
 
 impl<const D: usize> Default for Point<D> {
@@ -391,4 +415,18 @@ impl From<(i64, i64, i64)> for Point3D {
     fn from(coords: (i64, i64, i64)) -> Self {
         Self { coords: [coords.0, coords.1, coords.2] }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_neighbours() {
+        let neighbours: Vec<Point2D> = Point2D::new(0, 0).von_neumann_neighbours().collect();
+        assert_eq!(neighbours, vec![Point2D::new(-1, 0), Point2D::new(1, 0), Point2D::new(0, -1), Point2D::new(0, 1)]);
+        assert_eq!(Point2D::new(5, 5).moore_neighbours().count(), 8);
+        assert_eq!(Point3D::new(0, 0, 0).moore_neighbours().count(), 26);
+        assert!(Point3D::new(1, 2, 3).moore_neighbours().all(|neighbour| neighbour != Point3D::new(1, 2, 3)));
+    }
+}
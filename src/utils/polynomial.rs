@@ -0,0 +1,116 @@
+use num::rational::Ratio;
+use num::Zero;
+
+// Exact fractions, wide enough for values sampled far away from the known points
+pub type Rational = Ratio<i128>;
+
+// Value following the sequence, from its table of finite differences
+pub fn extrapolate_next(values: &[i64]) -> i64 {
+    differences(values)
+        .iter()
+        .map(|row| row.last().unwrap())
+        .sum()
+}
+
+// Value preceding the sequence, from its table of finite differences
+pub fn extrapolate_previous(values: &[i64]) -> i64 {
+    differences(values)
+        .iter()
+        .rev()
+        .fold(0, |previous, row| row[0] - previous)
+}
+
+// Rows of successive differences, stopping at the first row of zeros (excluded)
+fn differences(values: &[i64]) -> Vec<Vec<i64>> {
+    let mut rows = Vec::new();
+    let mut row = values.to_vec();
+    while row.iter().any(|value| *value != 0) {
+        let next_row = row.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        rows.push(row);
+        row = next_row;
+    }
+    rows
+}
+
+// Value at `x` of the polynomial of lowest degree going through the points, whose x must be distinct
+pub fn lagrange_interpolate(points: &[(i64, i64)], x: i64) -> Rational {
+    let x = i128::from(x);
+    let mut value = Rational::zero();
+    for (i, (x_i, y_i)) in points.iter().enumerate() {
+        let mut term = Rational::from_integer(i128::from(*y_i));
+        for (j, (x_j, _)) in points.iter().enumerate() {
+            if i != j {
+                term *= Rational::new(x - i128::from(*x_j), i128::from(*x_i) - i128::from(*x_j));
+            }
+        }
+        value += term;
+    }
+    value
+}
+
+// a * x^2 + b * x + c
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quadratic {
+    pub a: Rational,
+    pub b: Rational,
+    pub c: Rational,
+}
+
+impl Quadratic {
+    // Coefficients from the interpolated values at -1, 0 and 1, the x must be distinct
+    pub fn fit(points: [(i64, i64); 3]) -> Self {
+        let minus_one = lagrange_interpolate(&points, -1);
+        let zero = lagrange_interpolate(&points, 0);
+        let one = lagrange_interpolate(&points, 1);
+        let two = Rational::from_integer(2);
+        Quadratic {
+            a: (one + minus_one) / two - zero,
+            b: (one - minus_one) / two,
+            c: zero,
+        }
+    }
+
+    // Samples taken at `start`, `start + step` and `start + 2 * step`
+    pub fn fit_regular(start: i64, step: i64, values: [i64; 3]) -> Self {
+        Quadratic::fit([
+            (start, values[0]),
+            (start + step, values[1]),
+            (start + step * 2, values[2]),
+        ])
+    }
+
+    pub fn eval(&self, x: i64) -> Rational {
+        let x = Rational::from_integer(i128::from(x));
+        (self.a * x + self.b) * x + self.c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate() {
+        assert_eq!(extrapolate_next(&[1, 3, 6, 10, 15, 21]), 28);
+        assert_eq!(extrapolate_previous(&[10, 13, 16, 21, 30, 45]), 5);
+        assert_eq!(extrapolate_next(&[0, 0, 0]), 0);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        // y = x^2 / 2 - 3x + 1
+        let f = |x: i64| Rational::new(i128::from(x * x), 2) - Rational::from_integer(i128::from(3 * x - 1));
+        let points = [(0, 1), (2, -3), (4, -3)];
+        assert_eq!(lagrange_interpolate(&points, 1), f(1));
+        assert_eq!(lagrange_interpolate(&points, 26501365), f(26501365));
+
+        let quadratic = Quadratic::fit(points);
+        assert_eq!(quadratic.a, Rational::new(1, 2));
+        assert_eq!(quadratic.b, Rational::from_integer(-3));
+        assert_eq!(quadratic.c, Rational::from_integer(1));
+        assert_eq!(Quadratic::fit_regular(0, 2, [1, -3, -3]), quadratic);
+        assert_eq!(quadratic.eval(-7), f(-7));
+        assert_eq!(quadratic.eval(1), f(1));
+        assert_eq!(quadratic.eval(26501365), f(26501365));
+    }
+}
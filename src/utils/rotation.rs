@@ -0,0 +1,115 @@
+use std::ops::Mul;
+
+use crate::utils::Point3D;
+
+// One of the 24 rotations mapping axes onto axes, stored as a signed permutation matrix
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rotation3D {
+    matrix: [[i64; 3]; 3],
+}
+
+#[allow(dead_code)]
+impl Rotation3D {
+    pub const IDENTITY: Rotation3D = Rotation3D { matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]] };
+
+    // Quarter turns around each axis, counterclockwise when looking from the positive side
+    pub const QUARTER_X: Rotation3D = Rotation3D { matrix: [[1, 0, 0], [0, 0, -1], [0, 1, 0]] };
+    pub const QUARTER_Y: Rotation3D = Rotation3D { matrix: [[0, 0, 1], [0, 1, 0], [-1, 0, 0]] };
+    pub const QUARTER_Z: Rotation3D = Rotation3D { matrix: [[0, -1, 0], [1, 0, 0], [0, 0, 1]] };
+
+    // Every orientation, starting with the identity
+    pub fn all() -> Vec<Rotation3D> {
+        const PERMUTATIONS: [[usize; 3]; 6] = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+
+        let mut rotations = Vec::with_capacity(24);
+        for permutation in PERMUTATIONS {
+            for signs in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, column) in permutation.into_iter().enumerate() {
+                    matrix[row][column] = if signs & (1 << row) == 0 { 1 } else { -1 };
+                }
+                let rotation = Rotation3D { matrix };
+                // Signed permutations with a negative determinant are reflections
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+        rotations
+    }
+
+    pub fn apply(&self, point: Point3D) -> Point3D {
+        let mut coords = [0; 3];
+        for (coord, row) in coords.iter_mut().zip(self.matrix.iter()) {
+            *coord = row[0] * point.x() + row[1] * point.y() + row[2] * point.z();
+        }
+        Point3D::from(coords)
+    }
+
+    // Applies `other` first, then `self`
+    pub fn compose(&self, other: &Rotation3D) -> Rotation3D {
+        let mut matrix = [[0; 3]; 3];
+        for (row, matrix_row) in matrix.iter_mut().enumerate() {
+            for (column, value) in matrix_row.iter_mut().enumerate() {
+                *value = (0..3).map(|i| self.matrix[row][i] * other.matrix[i][column]).sum();
+            }
+        }
+        Rotation3D { matrix }
+    }
+
+    // Rotation matrices are orthogonal, the inverse is the transpose
+    pub fn inverse(&self) -> Rotation3D {
+        let mut matrix = [[0; 3]; 3];
+        for (row, matrix_row) in matrix.iter_mut().enumerate() {
+            for (column, value) in matrix_row.iter_mut().enumerate() {
+                *value = self.matrix[column][row];
+            }
+        }
+        Rotation3D { matrix }
+    }
+
+    fn determinant(&self) -> i64 {
+        let m = &self.matrix;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+}
+
+impl Mul<Point3D> for Rotation3D {
+    type Output = Point3D;
+
+    fn mul(self, point: Point3D) -> Point3D {
+        self.apply(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_rotations() {
+        let rotations = Rotation3D::all();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations[0], Rotation3D::IDENTITY);
+
+        // Every orientation maps a point with distinct coords to a different point
+        let point = Point3D::new(1, 2, 3);
+        let points: HashSet<Point3D> = rotations.iter().map(|rotation| rotation.apply(point)).collect();
+        assert_eq!(points.len(), 24);
+
+        assert_eq!(Rotation3D::QUARTER_Z * Point3D::new(1, 0, 0), Point3D::new(0, 1, 0));
+        assert_eq!(Rotation3D::QUARTER_X * Point3D::new(0, 1, 0), Point3D::new(0, 0, 1));
+        assert_eq!(Rotation3D::QUARTER_Y * Point3D::new(0, 0, 1), Point3D::new(1, 0, 0));
+
+        let half_turn = Rotation3D::QUARTER_Z.compose(&Rotation3D::QUARTER_Z);
+        assert_eq!(half_turn * point, Point3D::new(-1, -2, 3));
+        for rotation in rotations.iter() {
+            assert_eq!(rotation.compose(&rotation.inverse()), Rotation3D::IDENTITY);
+            assert!(rotations.contains(&rotation.compose(&Rotation3D::QUARTER_X)));
+        }
+    }
+}
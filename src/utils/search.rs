@@ -0,0 +1,228 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+
+const NO_PARENT: usize = usize::MAX;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchResult<S> {
+    pub cost: u64,
+    // States from start to goal, both included
+    pub path: Vec<S>,
+}
+
+pub fn dijkstra<S, I>(start: S, successors: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Option<SearchResult<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    a_star(start, successors, is_goal, |_| 0)
+}
+
+// The heuristic must never overestimate the remaining cost to a goal. It doesn't have to be consistent,
+// a state is expanded again whenever a cheaper path to it turns up.
+pub fn a_star<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool, mut heuristic: impl FnMut(&S) -> u64) -> Option<SearchResult<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut states = States::new(start.clone());
+    let mut costs = vec![0];
+    let mut open_set = BinaryHeap::new();
+    open_set.push(Reverse((heuristic(&start), 0, 0)));
+
+    while let Some(Reverse((_, cost, index))) = open_set.pop() {
+        // A cheaper path to this state was found after it was pushed
+        if cost > costs[index] {
+            continue;
+        }
+
+        let state = states.get(index).clone();
+        if is_goal(&state) {
+            return Some(SearchResult {
+                cost,
+                path: states.path_to(index),
+            });
+        }
+
+        for (next_state, transition_cost) in successors(&state) {
+            let new_cost = cost + transition_cost;
+            let (next_index, is_new) = states.insert(next_state, index);
+            if is_new {
+                costs.push(new_cost);
+            } else if new_cost >= costs[next_index] {
+                continue;
+            } else {
+                costs[next_index] = new_cost;
+                states.parents[next_index] = index;
+            }
+            open_set.push(Reverse((new_cost + heuristic(states.get(next_index)), new_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+// Same as `dijkstra` for states that map to distinct indices below `state_count`,
+// costs are stored in arrays instead of a hash map
+pub fn dijkstra_indexed<S, I>(start: S, state_count: usize, mut index_of: impl FnMut(&S) -> usize, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<SearchResult<S>>
+where
+    S: Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut costs = vec![u64::MAX; state_count];
+    let mut parents = vec![NO_PARENT; state_count];
+    let mut states: Vec<Option<S>> = vec![None; state_count];
+
+    let start_index = index_of(&start);
+    costs[start_index] = 0;
+    states[start_index] = Some(start);
+    let mut open_set = BinaryHeap::from([Reverse((0, start_index))]);
+
+    while let Some(Reverse((cost, index))) = open_set.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+
+        let state = states[index].clone().unwrap();
+        if is_goal(&state) {
+            let mut path = vec![state];
+            let mut index = index;
+            while parents[index] != NO_PARENT {
+                index = parents[index];
+                path.push(states[index].clone().unwrap());
+            }
+            path.reverse();
+            return Some(SearchResult { cost, path });
+        }
+
+        for (next_state, transition_cost) in successors(&state) {
+            let new_cost = cost + transition_cost;
+            let next_index = index_of(&next_state);
+            if new_cost < costs[next_index] {
+                costs[next_index] = new_cost;
+                parents[next_index] = index;
+                states[next_index] = Some(next_state);
+                open_set.push(Reverse((new_cost, next_index)));
+            }
+        }
+    }
+
+    None
+}
+
+// Every transition costs 1
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<SearchResult<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut states = States::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+
+    while let Some((index, cost)) = queue.pop_front() {
+        let state = states.get(index).clone();
+        if is_goal(&state) {
+            return Some(SearchResult {
+                cost,
+                path: states.path_to(index),
+            });
+        }
+
+        for next_state in successors(&state) {
+            let (next_index, is_new) = states.insert(next_state, index);
+            if is_new {
+                queue.push_back((next_index, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+// Every visited state gets an index, parents are stored by index to rebuild the path
+struct States<S> {
+    indices: HashMap<S, usize>,
+    states: Vec<S>,
+    parents: Vec<usize>,
+}
+
+impl<S: Clone + Eq + Hash> States<S> {
+    fn new(start: S) -> Self {
+        States {
+            indices: HashMap::from([(start.clone(), 0)]),
+            states: vec![start],
+            parents: vec![NO_PARENT],
+        }
+    }
+
+    fn get(&self, index: usize) -> &S {
+        &self.states[index]
+    }
+
+    fn insert(&mut self, state: S, parent: usize) -> (usize, bool) {
+        match self.indices.entry(state) {
+            Entry::Occupied(entry) => (*entry.get(), false),
+            Entry::Vacant(entry) => {
+                let index = self.states.len();
+                self.states.push(entry.key().clone());
+                self.parents.push(parent);
+                entry.insert(index);
+                (index, true)
+            },
+        }
+    }
+
+    fn path_to(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![self.states[index].clone()];
+        while self.parents[index] != NO_PARENT {
+            index = self.parents[index];
+            path.push(self.states[index].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        // Reach 10 from 1 by adding 1 (cost 1) or doubling (cost 2)
+        let successors = |n: &u64| [(n + 1, 1), (n * 2, 2)].into_iter().filter(|(n, _)| *n <= 10);
+
+        let result = dijkstra(1, successors, |n| *n == 10).unwrap();
+        assert_eq!(result.cost, 6);
+        assert_eq!(result.path.first(), Some(&1));
+        assert_eq!(result.path.last(), Some(&10));
+
+        let result = a_star(1, successors, |n| *n == 10, |n| u64::from(*n < 10)).unwrap();
+        assert_eq!(result.cost, 6);
+
+        let result = dijkstra_indexed(1, 11, |n| *n as usize, successors, |n| *n == 10).unwrap();
+        assert_eq!(result.cost, 6);
+        assert_eq!(result.path.first(), Some(&1));
+        assert_eq!(result.path.last(), Some(&10));
+        assert_eq!(dijkstra_indexed(1, 11, |n| *n as usize, successors, |n| *n == 11), None);
+
+        let result = bfs(1, |n| [n + 1, n * 2].into_iter().filter(|n| *n <= 10), |n| *n == 10).unwrap();
+        assert_eq!(result, SearchResult { cost: 4, path: vec![1, 2, 4, 5, 10] });
+
+        assert_eq!(dijkstra(1, successors, |n| *n == 11), None);
+
+        // Admissible but inconsistent heuristic, `a` is first reached by its most expensive path
+        let successors = |c: &char| match c {
+            's' => vec![('a', 4), ('b', 1)],
+            'b' => vec![('a', 1)],
+            'a' => vec![('g', 5)],
+            _ => vec![],
+        };
+        let heuristic = |c: &char| if *c == 'b' { 4 } else { 0 };
+        let result = a_star('s', successors, |c| *c == 'g', heuristic).unwrap();
+        assert_eq!(result, SearchResult { cost: 7, path: vec!['s', 'b', 'a', 'g'] });
+        assert_eq!(bfs(3, |_| [], |n| *n == 3).map(|result| result.cost), Some(0));
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::ops::Index;
+
+use crate::utils::{BoundMD, Point};
+
+// Unbounded grid storing only the cells that were set, every other cell has the background value
+#[derive(Clone, Debug)]
+pub struct SparseGrid<const D: usize, T> {
+    cells: HashMap<Point<D>, T>,
+    background: T,
+    // Smallest bound containing every cell that was set, it doesn't shrink when cells are removed
+    bound: Option<BoundMD<D>>,
+}
+
+#[allow(dead_code)]
+impl<const D: usize, T> SparseGrid<D, T> {
+    pub fn new(background: T) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            background,
+            bound: None,
+        }
+    }
+
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    // Changes the value of every cell that was not set
+    pub fn set_background(&mut self, background: T) {
+        self.background = background;
+    }
+
+    pub fn bound(&self) -> Option<BoundMD<D>> {
+        self.bound
+    }
+
+    // Number of cells that were set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, coords: impl Into<Point<D>>) -> &T {
+        self.cells.get(&coords.into()).unwrap_or(&self.background)
+    }
+
+    pub fn contains(&self, coords: impl Into<Point<D>>) -> bool {
+        self.cells.contains_key(&coords.into())
+    }
+
+    pub fn insert(&mut self, coords: impl Into<Point<D>>, value: T) -> Option<T> {
+        let coords = coords.into();
+        self.grow(coords);
+        self.cells.insert(coords, value)
+    }
+
+    pub fn remove(&mut self, coords: impl Into<Point<D>>) -> Option<T> {
+        self.cells.remove(&coords.into())
+    }
+
+    // Cells that were set, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point<D>, &T)> {
+        self.cells.iter().map(|(coords, value)| (*coords, value))
+    }
+
+    fn grow(&mut self, coords: Point<D>) {
+        match &mut self.bound {
+            Some(bound) => bound.append_point(coords),
+            None => self.bound = Some(BoundMD::new(coords, Point::ONE)),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<const D: usize, T: Clone> SparseGrid<D, T> {
+    // The cell is set to the background value first if needed
+    pub fn get_mut(&mut self, coords: impl Into<Point<D>>) -> &mut T {
+        let coords = coords.into();
+        self.grow(coords);
+        match self.cells.entry(coords) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(self.background.clone()),
+        }
+    }
+}
+
+impl<const D: usize, I: Into<Point<D>>, T> Index<I> for SparseGrid<D, T> {
+    type Output = T;
+
+    fn index(&self, coords: I) -> &T {
+        self.get(coords)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Point2D;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<2, i32> = SparseGrid::new(0);
+        assert_eq!(grid.bound(), None);
+        assert_eq!(grid[[1_000_000, -5]], 0);
+
+        grid.insert([2, 3], 5);
+        *grid.get_mut([-1, 7]) += 2;
+        *grid.get_mut([2, 3]) += 1;
+        assert_eq!(grid[[2, 3]], 6);
+        assert_eq!(grid[Point2D::new(-1, 7)], 2);
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bound(), Some(BoundMD::from_min_max([-1, 3], [2, 7])));
+
+        grid.set_background(-1);
+        assert_eq!(grid[[0, 0]], -1);
+        assert_eq!(grid.remove([2, 3]), Some(6));
+        assert_eq!(grid[[2, 3]], -1);
+        assert_eq!(grid.iter().collect::<Vec<_>>(), vec![(Point2D::new(-1, 7), &2)]);
+    }
+}
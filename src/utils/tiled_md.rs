@@ -0,0 +1,87 @@
+use std::ops::Index;
+
+use crate::utils::{ArrayMD, Point};
+
+// Read only view repeating an array infinitely in every direction
+pub struct TiledMD<'a, const D: usize, T> {
+    array: &'a ArrayMD<D, T>,
+}
+
+#[allow(dead_code)]
+impl<'a, const D: usize, T> TiledMD<'a, D, T> {
+    pub fn new(array: &'a ArrayMD<D, T>) -> Self {
+        TiledMD { array }
+    }
+
+    // Size of a single tile
+    pub fn tile_sizes(&self) -> Point<D> {
+        self.array.sizes()
+    }
+
+    pub fn get(&self, coords: impl Into<Point<D>>) -> &'a T {
+        self.array.get_wrap(coords)
+    }
+
+    // Coords inside the base tile
+    pub fn base_coords(&self, coords: impl Into<Point<D>>) -> Point<D> {
+        self.array.wrap_coords(coords)
+    }
+
+    // Index of the tile containing the coords, the base tile is at zero
+    pub fn tile(&self, coords: impl Into<Point<D>>) -> Point<D> {
+        let mut coords = coords.into();
+        for d in 0..D {
+            coords[d] = coords[d].div_euclid(self.array.size(d));
+        }
+        coords
+    }
+
+    // Neighbours sharing a face with their values, coords are not wrapped
+    pub fn von_neumann_neighbours(&self, coords: impl Into<Point<D>>) -> impl Iterator<Item = (Point<D>, &'a T)> + '_ {
+        coords.into()
+            .von_neumann_neighbours()
+            .map(|neighbour| (neighbour, self.get(neighbour)))
+    }
+
+    // Neighbours sharing a face, an edge or a corner with their values, coords are not wrapped
+    pub fn moore_neighbours(&self, coords: impl Into<Point<D>>) -> impl Iterator<Item = (Point<D>, &'a T)> + '_ {
+        coords.into()
+            .moore_neighbours()
+            .map(|neighbour| (neighbour, self.get(neighbour)))
+    }
+}
+
+impl<const D: usize, I: Into<Point<D>>, T> Index<I> for TiledMD<'_, D, T> {
+    type Output = T;
+
+    fn index(&self, coords: I) -> &T {
+        self.get(coords)
+    }
+}
+
+#[allow(dead_code)]
+impl<const D: usize, T> ArrayMD<D, T> {
+    pub fn tiled(&self) -> TiledMD<'_, D, T> {
+        TiledMD::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{Array2D, Point2D};
+
+    #[test]
+    fn test_tiled() {
+        let array = Array2D::from_str("ab\ncd", false).unwrap();
+        let tiled = array.tiled();
+        assert_eq!(tiled[[0, 0]], 'a');
+        assert_eq!(tiled[[5, 3]], 'd');
+        assert_eq!(tiled[[-1, -2]], 'b');
+        assert_eq!(tiled.base_coords([-1, -2]), Point2D::new(1, 0));
+        assert_eq!(tiled.tile([-1, -2]), Point2D::new(-1, -1));
+        assert_eq!(tiled.tile([3, 1]), Point2D::new(1, 0));
+
+        let neighbours: Vec<(Point2D, char)> = tiled.von_neumann_neighbours([0, 0]).map(|(coords, c)| (coords, *c)).collect();
+        assert_eq!(neighbours, vec![(Point2D::new(-1, 0), 'b'), (Point2D::new(1, 0), 'b'), (Point2D::new(0, -1), 'c'), (Point2D::new(0, 1), 'c')]);
+    }
+}